@@ -0,0 +1,76 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use adventofcode_rust::days::day05;
+use adventofcode_rust::days::day11::Day11;
+use adventofcode_rust::days::day14::Stream;
+use adventofcode_rust::days::day16;
+use adventofcode_rust::hash;
+use adventofcode_rust::solution::Solution;
+
+fn read_input(day: u32) -> String {
+    fs::read_to_string(format!("data/day{:02}.txt", day)).unwrap()
+}
+
+fn bench_day05(c: &mut Criterion) {
+    c.bench_function("day05 md5 of 10000 door ids", |b| {
+        b.iter(|| {
+            (0..10_000)
+                .map(|i| hash::md5(format!("abc{}", i)))
+                .filter(day05::is_interesting)
+                .count()
+        })
+    });
+
+    let mut group = c.benchmark_group("day05");
+    group.sample_size(10);
+    group.bench_function("first interesting hash of abc", |b| {
+        b.iter(|| day05::interesting_hashes(black_box("abc")).next())
+    });
+    group.finish();
+}
+
+fn bench_day11(c: &mut Criterion) {
+    let input = Day11::parse(&read_input(11)).unwrap();
+
+    let mut group = c.benchmark_group("day11");
+    group.sample_size(10);
+    group.bench_function("part 1", |b| b.iter(|| Day11::part1(&input)));
+    group.bench_function("part 2", |b| b.iter(|| Day11::part2(&input)));
+    group.finish();
+}
+
+fn bench_day14(c: &mut Criterion) {
+    c.bench_function("day14 stretched hash", |b| {
+        b.iter(|| hash::md5_hex_stretched(black_box("abc0"), 2016))
+    });
+    c.bench_function("day14 key check", |b| {
+        b.iter(|| Stream::new("abc").is_key_part_one(black_box(39)))
+    });
+
+    let mut group = c.benchmark_group("day14");
+    group.sample_size(10);
+    group.bench_function("stretched key check", |b| {
+        b.iter(|| Stream::new("abc").is_key_part_two(black_box(10)))
+    });
+    group.finish();
+}
+
+fn bench_day16(c: &mut Criterion) {
+    let input = day16::Day16::parse(&read_input(16)).unwrap();
+
+    c.bench_function("day16 checksum of 272 bytes", |b| {
+        b.iter(|| day16::checksum_for_disk(&input, 272))
+    });
+
+    let mut group = c.benchmark_group("day16");
+    group.sample_size(10);
+    group.bench_function("checksum of 35651584 bytes", |b| {
+        b.iter(|| day16::checksum_for_disk(&input, 35651584))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_day05, bench_day11, bench_day14, bench_day16);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+//! Types a bathroom code on a keypad read from a layout file, so that new keypad shapes can be
+//! tried without recompiling.
+//!
+//!     cargo run --example keypad -- data/keypads/diamond.txt 5 data/day02.txt
+
+use std::env;
+use std::fs;
+use std::process;
+
+use adventofcode_rust::days::day02::{self, Day02, Keypad};
+use adventofcode_rust::solution::Solution;
+
+const USAGE: &str = "Usage: keypad <LAYOUT> <START KEY> <INPUT>";
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))
+}
+
+fn run(args: &[String]) -> Result<String, String> {
+    let (layout, start, input) = match args {
+        [layout, start, input] => (layout, start, input),
+        _ => return Err(USAGE.to_string()),
+    };
+    let start = match start.chars().collect::<Vec<char>>()[..] {
+        [start] => start,
+        _ => return Err(format!("Invalid start key: {}", start)),
+    };
+
+    let keypad = Keypad::parse(&read(layout)?).map_err(|e| format!("{}: {}", layout, e))?;
+    let instructions = Day02::parse(&read(input)?).map_err(|e| format!("{}: {}", input, e))?;
+    day02::get_bathroom_code(&instructions, &keypad, start)
+        .ok_or_else(|| format!("There is no key {} on the keypad", start))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => println!("{}", code),
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    }
+}
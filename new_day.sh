@@ -3,20 +3,46 @@ if [[ $# -ne 1 ]]; then
     exit 1
 fi
 
-filename_src=$(printf "./src/bin/day%02d.rs" $1)
-filename_data=$(printf "./data/day%02d.txt" $1)
+day=$(printf "%02d" $1)
+filename_src="./src/days/day${day}.rs"
+filename_data="./data/day${day}.txt"
+filename_mod="./src/days/mod.rs"
+
+if [[ -e $filename_src ]]; then
+    echo "$filename_src already exists"
+    exit 1
+fi
 
 cat << RUST > $filename_src
-use std::io;
-use std::io::prelude::*;
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day${day};
 
-fn main() -> io::Result<()> {
-    let mut input = String::new();
-    io::stdin().lock().read_to_string(&mut input).unwrap();
-    let input = &input[..];
+impl Solution for Day${day} {
+    type Input = String;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        Err(Error::Invalid("part 1 is not solved yet".to_string()))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Err(Error::Invalid("part 2 is not solved yet".to_string()))
+    }
 }
 RUST
 
+# Insert the module declaration before the first later day, or after the last earlier one.
+awk -v line="pub mod day${day};" '
+    /^pub mod day[0-9]+;$/ { seen = 1; if (!done && $0 > line) { print line; done = 1 } }
+    !/^pub mod day[0-9]+;$/ && seen && !done { print line; done = 1 }
+    { print }
+' $filename_mod > $filename_mod.tmp && mv $filename_mod.tmp $filename_mod
+
 touch $filename_data
+
+echo "Register Day${day} in DAYS in $filename_mod to make it available to aoc."
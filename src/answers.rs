@@ -0,0 +1,59 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Part};
+
+/// Known answers per day and part, as stored in `answers.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = 236
+/// part2 = 182
+/// ```
+pub struct Answers {
+    table: toml::value::Table,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers> {
+        match input.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => Ok(Answers { table }),
+            Ok(_value) => Err(Error::Invalid("expected a table of days".to_string())),
+            Err(e) => {
+                let (line, column) = e.line_col().unwrap_or((0, 0));
+                let message = e.to_string();
+                let message = match message.rfind(" at line ") {
+                    Some(i) => message[..i].to_string(),
+                    None => message,
+                };
+                Err(Error::Parse {
+                    line: line + 1,
+                    column: column + 1,
+                    message,
+                })
+            }
+        }
+    }
+
+    pub fn expected(&self, day: u32, part: Part) -> Option<String> {
+        let day = self.table.get(&format!("day{:02}", day))?;
+        match day.get(format!("part{}", part))? {
+            toml::Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    /// Answers are compared by their printed form, so `"45973"` and `45973` are equivalent.
+    pub fn check(&self, day: u32, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Missing,
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::parse::{lines, parse_i64};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, one_of},
+    combinator::map,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Register {
+    fn index(self) -> usize {
+        match self {
+            Register::A => 0,
+            Register::B => 1,
+            Register::C => 2,
+            Register::D => 3,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+/// An assembunny instruction. Operands are kept general so that instructions which make no sense,
+/// such as copying into a constant, can be represented and skipped instead of rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+}
+
+impl Instruction {
+    /// The instruction that `tgl` turns this one into.
+    fn toggled(self) -> Instruction {
+        match self {
+            Instruction::Inc(x) => Instruction::Dec(x),
+            Instruction::Dec(x) | Instruction::Tgl(x) | Instruction::Out(x) => Instruction::Inc(x),
+            Instruction::Jnz(x, y) => Instruction::Cpy(x, y),
+            Instruction::Cpy(x, y) => Instruction::Jnz(x, y),
+        }
+    }
+}
+
+/// The nested loop `cpy x i; inc t; dec i; jnz i -2; dec o; jnz o -5`, which adds `x * o` to
+/// `t` and leaves `i` and `o` at zero.
+struct Multiplication {
+    factor: Operand,
+    target: Register,
+    inner: Register,
+    outer: Register,
+}
+
+/// An assembunny machine.
+#[derive(Clone, Debug)]
+pub struct Machine {
+    program: Vec<Instruction>,
+    registers: [i64; 4],
+    pc: i64,
+    optimise: bool,
+    output: Option<i64>,
+    toggled: bool,
+}
+
+/// Everything that decides what a machine does next: its registers and program counter, and its
+/// program once `tgl` has changed it. Until then the program is the one the machine started
+/// with, so it is left out to keep states cheap to compare.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct State {
+    registers: [i64; 4],
+    pc: i64,
+    program: Option<Vec<Instruction>>,
+}
+
+impl Machine {
+    pub fn new(program: Vec<Instruction>) -> Machine {
+        Machine {
+            program,
+            registers: [0; 4],
+            pc: 0,
+            optimise: true,
+            output: None,
+            toggled: false,
+        }
+    }
+
+    /// Enables or disables executing recognised multiplication loops in a single step. It is
+    /// enabled by default.
+    pub fn set_optimise(&mut self, optimise: bool) {
+        self.optimise = optimise;
+    }
+
+    pub fn register(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    pub fn set_register(&mut self, register: Register, value: i64) {
+        self.registers[register.index()] = value;
+    }
+
+    pub fn registers(&self) -> [i64; 4] {
+        self.registers
+    }
+
+    pub fn pc(&self) -> i64 {
+        self.pc
+    }
+
+    pub fn state(&self) -> State {
+        State {
+            registers: self.registers,
+            pc: self.pc,
+            program: if self.toggled {
+                Some(self.program.clone())
+            } else {
+                None
+            },
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc < 0 || self.pc >= self.program.len() as i64
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(r) => self.register(r),
+            Operand::Value(v) => v,
+        }
+    }
+
+    fn multiplication(&self) -> Option<Multiplication> {
+        use self::Instruction::{Cpy, Dec, Inc, Jnz};
+        use self::Operand::{Register as R, Value as V};
+
+        let pc = self.pc as usize;
+        let window = self.program.get(pc..pc + 6)?;
+        let (factor, inner) = match window[0] {
+            Cpy(factor, R(inner)) => (factor, inner),
+            _ => return None,
+        };
+        let target = match window[1] {
+            Inc(R(target)) => target,
+            _ => return None,
+        };
+        let outer = match window[4] {
+            Dec(R(outer)) => outer,
+            _ => return None,
+        };
+        let is_loop = window[2] == Dec(R(inner))
+            && window[3] == Jnz(R(inner), V(-2))
+            && window[5] == Jnz(R(outer), V(-5));
+
+        // The shortcut is only equivalent if the registers are distinct and both loops count
+        // down to zero.
+        let distinct = target != inner && target != outer && inner != outer;
+        let factor_is_free = ![target, inner, outer].iter().any(|&r| factor == R(r));
+        if is_loop
+            && distinct
+            && factor_is_free
+            && self.value(factor) > 0
+            && self.register(outer) > 0
+        {
+            Some(Multiplication {
+                factor,
+                target,
+                inner,
+                outer,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Executes the instruction at the program counter, or a whole multiplication loop starting
+    /// there if optimisation is enabled. Returns `false` if the machine had already halted.
+    pub fn step(&mut self) -> bool {
+        if self.is_halted() {
+            return false;
+        }
+
+        if self.optimise {
+            if let Some(m) = self.multiplication() {
+                self.registers[m.target.index()] += self.value(m.factor) * self.register(m.outer);
+                self.set_register(m.inner, 0);
+                self.set_register(m.outer, 0);
+                self.pc += 6;
+                return true;
+            }
+        }
+
+        let mut next_pc = self.pc + 1;
+        match self.program[self.pc as usize] {
+            Instruction::Cpy(source, Operand::Register(r)) => {
+                self.set_register(r, self.value(source));
+            }
+            Instruction::Inc(Operand::Register(r)) => {
+                self.registers[r.index()] += 1;
+            }
+            Instruction::Dec(Operand::Register(r)) => {
+                self.registers[r.index()] -= 1;
+            }
+            Instruction::Jnz(condition, offset) => {
+                if self.value(condition) != 0 {
+                    next_pc = self.pc + self.value(offset);
+                }
+            }
+            Instruction::Out(value) => {
+                self.output = Some(self.value(value));
+            }
+            Instruction::Tgl(offset) => {
+                let target = self.pc + self.value(offset);
+                if target >= 0 && target < self.program.len() as i64 {
+                    let toggled = self.program[target as usize].toggled();
+                    self.program[target as usize] = toggled;
+                    self.toggled = true;
+                }
+            }
+            Instruction::Cpy(_, Operand::Value(_))
+            | Instruction::Inc(Operand::Value(_))
+            | Instruction::Dec(Operand::Value(_)) => {}
+        }
+        self.pc = next_pc;
+
+        true
+    }
+
+    pub fn run(&mut self) {
+        while self.step() {}
+    }
+
+    /// Runs until the next `out` instruction and returns the value it transmitted. Returns `None`
+    /// if the machine halts first, or if it gets back to a state it was in since the call started
+    /// without transmitting anything, as it would then loop silently forever.
+    ///
+    /// Silent loops are found with Brent's cycle detection, which only keeps one earlier state
+    /// around, so memory stays constant however long the machine runs before transmitting.
+    pub fn next_output(&mut self) -> Option<i64> {
+        let mut saved = self.state();
+        let mut power = 1;
+        let mut steps = 0;
+        while self.step() {
+            if let Some(value) = self.output.take() {
+                return Some(value);
+            }
+            let state = self.state();
+            if state == saved {
+                return None;
+            }
+            steps += 1;
+            if steps == power {
+                saved = state;
+                power *= 2;
+                steps = 0;
+            }
+        }
+        None
+    }
+}
+
+fn parse_register(input: &str) -> IResult<&str, Register> {
+    let register = |c| match c {
+        'a' => Register::A,
+        'b' => Register::B,
+        'c' => Register::C,
+        'd' => Register::D,
+        _ => unreachable!(),
+    };
+    map(one_of("abcd"), register)(input)
+}
+
+fn parse_operand(input: &str) -> IResult<&str, Operand> {
+    alt((
+        map(parse_register, Operand::Register),
+        map(parse_i64, Operand::Value),
+    ))(input)
+}
+
+fn parse_operands(input: &str) -> IResult<&str, (Operand, Operand)> {
+    separated_pair(parse_operand, char(' '), parse_operand)(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        map(preceded(tag("cpy "), parse_operands), |(x, y)| {
+            Instruction::Cpy(x, y)
+        }),
+        map(preceded(tag("inc "), parse_operand), Instruction::Inc),
+        map(preceded(tag("dec "), parse_operand), Instruction::Dec),
+        map(preceded(tag("jnz "), parse_operands), |(x, y)| {
+            Instruction::Jnz(x, y)
+        }),
+        map(preceded(tag("tgl "), parse_operand), Instruction::Tgl),
+        map(preceded(tag("out "), parse_operand), Instruction::Out),
+    ))(input)
+}
+
+pub fn parse_program(input: &str) -> IResult<&str, Vec<Instruction>> {
+    lines(parse_instruction)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::complete;
+
+    const EXAMPLE: &str = "cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a";
+
+    #[test]
+    fn test_parse_program() {
+        let program = complete(EXAMPLE, parse_program).unwrap();
+        assert_eq!(program.len(), 6);
+        assert_eq!(
+            program[0],
+            Instruction::Cpy(Operand::Value(41), Operand::Register(Register::A))
+        );
+        assert_eq!(
+            program[4],
+            Instruction::Jnz(Operand::Register(Register::A), Operand::Value(2))
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let mut machine = Machine::new(complete(EXAMPLE, parse_program).unwrap());
+        assert!(machine.step());
+        assert_eq!(machine.register(Register::A), 41);
+        assert_eq!(machine.pc(), 1);
+    }
+
+    #[test]
+    fn test_run_example() {
+        let mut machine = Machine::new(complete(EXAMPLE, parse_program).unwrap());
+        machine.run();
+        assert!(machine.is_halted());
+        assert_eq!(machine.register(Register::A), 42);
+        assert!(!machine.step());
+    }
+
+    #[test]
+    fn test_tgl() {
+        let program = "cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a";
+        let mut machine = Machine::new(complete(program, parse_program).unwrap());
+        machine.run();
+        assert_eq!(machine.register(Register::A), 3);
+    }
+
+    #[test]
+    fn test_multiplication_optimisation() {
+        let program = "cpy 7 b\ncpy 6 d\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5";
+        let program = complete(program, parse_program).unwrap();
+        let mut results = Vec::new();
+        for &optimise in [true, false].iter() {
+            let mut machine = Machine::new(program.clone());
+            machine.set_optimise(optimise);
+            let mut steps = 0;
+            while machine.step() {
+                steps += 1;
+            }
+            results.push((machine.registers(), steps));
+        }
+        assert_eq!(results[0].0, [42, 7, 0, 0]);
+        assert_eq!(results[0].0, results[1].0);
+        assert_eq!(results[0].1, 3);
+        assert_eq!(results[1].1, 2 + 6 * (1 + 7 * 3 + 2));
+    }
+
+    #[test]
+    fn test_multiplication_needs_positive_counters() {
+        let program = "cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5";
+        let mut machine = Machine::new(complete(program, parse_program).unwrap());
+        machine.set_register(Register::B, 3);
+        machine.set_register(Register::D, 2);
+        assert!(machine.multiplication().is_some());
+        machine.set_register(Register::D, 0);
+        assert!(machine.multiplication().is_none());
+    }
+
+    #[test]
+    fn test_next_output() {
+        let program = complete("out 3\ninc a\nout a\ntgl -2", parse_program).unwrap();
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.next_output(), Some(3));
+        assert_eq!(machine.next_output(), Some(1));
+        assert_eq!(machine.next_output(), None);
+        assert_eq!(machine.registers(), [1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_next_output_detects_silent_loop() {
+        let program = complete("out 1\njnz 1 0", parse_program).unwrap();
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.next_output(), Some(1));
+        assert_eq!(machine.next_output(), None);
+
+        // A loop that keeps toggling an instruction back and forth.
+        let program = complete("out 1\ntgl 1\ninc a\njnz 1 -2", parse_program).unwrap();
+        let mut machine = Machine::new(program);
+        assert_eq!(machine.next_output(), Some(1));
+        assert_eq!(machine.next_output(), None);
+
+        // A loop that is only reached after a long silent countdown.
+        let program = complete("out 1\ncpy 1000 b\ndec b\njnz b -1\njnz 1 0", parse_program);
+        let mut machine = Machine::new(program.unwrap());
+        assert_eq!(machine.next_output(), Some(1));
+        assert_eq!(machine.next_output(), None);
+        assert_eq!(machine.registers(), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_invalid_instructions_are_skipped() {
+        let program = complete("cpy 1 2\ninc 3\ninc a", parse_program).unwrap();
+        let mut machine = Machine::new(program);
+        machine.run();
+        assert_eq!(machine.registers(), [1, 0, 0, 0]);
+    }
+}
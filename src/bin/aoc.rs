@@ -0,0 +1,296 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+use adventofcode_rust::answers::{Answers, Verdict};
+use adventofcode_rust::days::{self, Day};
+use adventofcode_rust::solution::{Answer, Part};
+
+const USAGE: &str = "Usage: aoc run <DAY> [--part <1|2>] [--input <FILE>]
+       aoc run --all [--part <1|2>]
+       aoc verify [<DAY>|--all] [--part <1|2>] [--answers <FILE>]";
+
+const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+enum Selection {
+    All,
+    Single(u32),
+}
+
+struct RunOptions {
+    selection: Selection,
+    parts: Vec<Part>,
+    input: Option<String>,
+}
+
+struct VerifyOptions {
+    selection: Selection,
+    parts: Vec<Part>,
+    answers: String,
+}
+
+enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+}
+
+fn parse_part(arg: Option<&String>) -> Result<Part, String> {
+    match arg.map(|s| s.as_str()) {
+        Some("1") => Ok(Part::One),
+        Some("2") => Ok(Part::Two),
+        Some(other) => Err(format!("Invalid part: {}", other)),
+        None => Err("Missing value for --part".to_string()),
+    }
+}
+
+fn parse_path(flag: &str, arg: Option<&String>) -> Result<String, String> {
+    match arg {
+        Some(path) => Ok(path.clone()),
+        None => Err(format!("Missing value for {}", flag)),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunOptions, String> {
+    let mut selection = None;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => selection = Some(Selection::All),
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--input" => input = Some(parse_path(arg, args.next())?),
+            day => selection = Some(parse_day(day)?),
+        }
+    }
+
+    let selection = selection.ok_or_else(|| "Missing day number or --all".to_string())?;
+    if let (Selection::All, Some(_input)) = (&selection, &input) {
+        return Err("--input cannot be combined with --all".to_string());
+    }
+
+    Ok(RunOptions {
+        selection,
+        parts,
+        input,
+    })
+}
+
+fn parse_verify_args(args: &[String]) -> Result<VerifyOptions, String> {
+    let mut selection = Selection::All;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut answers = DEFAULT_ANSWERS_PATH.to_string();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => selection = Selection::All,
+            "--part" => parts = vec![parse_part(args.next())?],
+            "--answers" => answers = parse_path(arg, args.next())?,
+            day => selection = parse_day(day)?,
+        }
+    }
+
+    Ok(VerifyOptions {
+        selection,
+        parts,
+        answers,
+    })
+}
+
+fn parse_day(arg: &str) -> Result<Selection, String> {
+    match arg.parse() {
+        Ok(day) => Ok(Selection::Single(day)),
+        Err(_e) => Err(format!("Invalid argument: {}", arg)),
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.split_first() {
+        Some((command, rest)) if command == "run" => Ok(Command::Run(parse_run_args(rest)?)),
+        Some((command, rest)) if command == "verify" => {
+            Ok(Command::Verify(parse_verify_args(rest)?))
+        }
+        Some((command, _rest)) => Err(format!("Unknown command: {}", command)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn select_days(selection: &Selection) -> Result<Vec<&'static Day>, String> {
+    match selection {
+        Selection::All => Ok(days::DAYS.iter().collect()),
+        Selection::Single(number) => match days::find(*number) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("Day {} is not implemented", number)),
+        },
+    }
+}
+
+fn default_input_path(day: &Day) -> String {
+    format!("data/day{:02}.txt", day.number)
+}
+
+fn print_answer(part: Part, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+fn solve_day(
+    day: &Day,
+    path: &str,
+    parts: &[Part],
+) -> Result<(Vec<(Part, Answer)>, Duration), String> {
+    let input = fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?;
+
+    let start = Instant::now();
+    let answers = (day.run)(&input, parts).map_err(|e| e.to_string())?;
+    Ok((answers, start.elapsed()))
+}
+
+fn run(options: RunOptions) -> bool {
+    let selected = match select_days(&options.selection) {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("{}", message);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    let mut total = Duration::new(0, 0);
+
+    for day in selected.iter() {
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => default_input_path(day),
+        };
+        if let Selection::All = options.selection {
+            if !Path::new(&path).exists() {
+                println!("Day {:02}: no input at {}, skipping", day.number, path);
+                continue;
+            }
+        }
+
+        println!(
+            "------------------------------------------------------------------------- DAY {:02}",
+            day.number
+        );
+        match solve_day(day, &path, &options.parts) {
+            Ok((answers, elapsed)) => {
+                for (part, answer) in answers.iter() {
+                    print_answer(*part, answer);
+                }
+                println!("Time: {:.3?}", elapsed);
+                total += elapsed;
+            }
+            Err(message) => {
+                eprintln!("Day {}: {}", day.number, message);
+                success = false;
+            }
+        }
+    }
+
+    if selected.len() > 1 {
+        println!("Total time: {:.3?}", total);
+    }
+
+    success
+}
+
+fn verify(options: VerifyOptions) -> bool {
+    let selected = match select_days(&options.selection) {
+        Ok(selected) => selected,
+        Err(message) => {
+            eprintln!("{}", message);
+            return false;
+        }
+    };
+
+    let answers = fs::read_to_string(&options.answers)
+        .map_err(|e| format!("Could not read {}: {}", options.answers, e))
+        .and_then(|s| Answers::parse(&s).map_err(|e| format!("{}: {}", options.answers, e)));
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(message) => {
+            eprintln!("{}", message);
+            return false;
+        }
+    };
+
+    let mut passed = 0;
+    let mut failed = 0;
+    let mut missing = 0;
+
+    for day in selected.iter() {
+        let path = default_input_path(day);
+        if !Path::new(&path).exists() {
+            println!("Day {:02}: missing, no input at {}", day.number, path);
+            missing += options.parts.len();
+            continue;
+        }
+
+        let results = match solve_day(day, &path, &options.parts) {
+            Ok((results, _elapsed)) => results,
+            Err(message) => {
+                println!("Day {:02}: error: {}", day.number, message);
+                failed += options.parts.len();
+                continue;
+            }
+        };
+
+        for (part, answer) in results.iter() {
+            match answers.check(day.number, *part, answer) {
+                Verdict::Pass => {
+                    println!("Day {:02} part {}: pass", day.number, part);
+                    passed += 1;
+                }
+                Verdict::Fail { expected } => {
+                    println!(
+                        "Day {:02} part {}: FAIL, expected {} but got {}",
+                        day.number, part, expected, answer
+                    );
+                    failed += 1;
+                }
+                Verdict::Missing => {
+                    println!(
+                        "Day {:02} part {}: missing, got {}",
+                        day.number, part, answer
+                    );
+                    missing += 1;
+                }
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    failed == 0
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let success = match command {
+        Command::Run(options) => run(options),
+        Command::Verify(options) => verify(options),
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
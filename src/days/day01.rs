@@ -0,0 +1,372 @@
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+use crate::turtle::{self, Instruction, Point, Turtle};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+/// Every lattice point the walk passes through, one unit step at a time, starting at the origin
+/// facing up. The step index of a point is its position in the walk.
+pub struct Walk {
+    points: Vec<Point>,
+}
+
+impl Walk {
+    pub fn trace(instructions: &[Instruction]) -> Walk {
+        let mut turtle = Turtle::new();
+        let mut points = vec![turtle.position()];
+
+        for i in instructions.iter() {
+            let dist = turtle.orient(i);
+            for _i in 0..dist.abs() {
+                turtle.walk(dist.signum());
+                points.push(turtle.position());
+            }
+        }
+
+        Walk { points }
+    }
+
+    /// The visited points together with their step index, in order.
+    pub fn visited(&self) -> impl Iterator<Item = (usize, Point)> + '_ {
+        self.points.iter().cloned().enumerate()
+    }
+
+    pub fn end(&self) -> Point {
+        *self.points.last().unwrap()
+    }
+
+    /// Every step that lands on a point visited before, in order.
+    pub fn revisits(&self) -> Vec<(usize, Point)> {
+        let mut seen: HashSet<Point> = HashSet::new();
+        self.visited()
+            .filter(|&(_step, point)| !seen.insert(point))
+            .collect()
+    }
+
+    /// The points where the walk changes direction, including the start and the end.
+    fn corners(&self) -> Vec<Point> {
+        let mut corners = vec![self.points[0]];
+        for window in self.points.windows(3) {
+            let (a, b, c) = (window[0], window[1], window[2]);
+            if (b.0 - a.0, b.1 - a.1) != (c.0 - b.0, c.1 - b.1) {
+                corners.push(b);
+            }
+        }
+        if self.points.len() > 1 {
+            corners.push(self.end());
+        }
+        corners
+    }
+
+    /// The walk as an SVG polyline, with the start, the end and the first revisited point marked.
+    pub fn to_svg(&self) -> String {
+        let BoundingBox { min, max } = self.bounding_box();
+        let polyline: Vec<String> = self
+            .corners()
+            .iter()
+            .map(|(x, y)| format!("{},{}", x, y))
+            .collect();
+        let marker = |(x, y): Point, class: &str, colour: &str| {
+            format!(
+                "  <circle class=\"{}\" cx=\"{}\" cy=\"{}\" r=\"0.4\" fill=\"{}\"/>\n",
+                class, x, y, colour
+            )
+        };
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n",
+            min.0 - 1,
+            min.1 - 1,
+            max.0 - min.0 + 2,
+            max.1 - min.1 + 2
+        );
+        svg += &format!(
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"0.2\"/>\n",
+            polyline.join(" ")
+        );
+        svg += &marker(self.points[0], "start", "green");
+        svg += &marker(self.end(), "end", "red");
+        if let Some(&(_step, point)) = self.revisits().first() {
+            svg += &marker(point, "revisit", "blue");
+        }
+        svg += "</svg>\n";
+        svg
+    }
+
+    /// The walk drawn on a grid covering its bounding box, one character per point: `S` for the
+    /// start, `E` for the end, `X` for the first revisited point and `#` for any other visited
+    /// point. Only suitable for small inputs, as the grid grows with the bounding box.
+    pub fn render(&self) -> String {
+        let BoundingBox { min, max } = self.bounding_box();
+        let width = (max.0 - min.0 + 1) as usize;
+        let height = (max.1 - min.1 + 1) as usize;
+        let cell = |(x, y): Point| ((x - min.0) as usize, (y - min.1) as usize);
+
+        let mut grid = Grid::new(width, height, '.');
+        for &point in self.points.iter() {
+            grid[cell(point)] = '#';
+        }
+        if let Some(&(_step, point)) = self.revisits().first() {
+            grid[cell(point)] = 'X';
+        }
+        grid[cell(self.end())] = 'E';
+        grid[cell(self.points[0])] = 'S';
+        grid.render(|_position, &c| c)
+    }
+
+    pub fn bounding_box(&self) -> BoundingBox {
+        let xs = self.points.iter().map(|p| p.0);
+        let ys = self.points.iter().map(|p| p.1);
+        BoundingBox {
+            min: (xs.clone().min().unwrap(), ys.clone().min().unwrap()),
+            max: (xs.max().unwrap(), ys.max().unwrap()),
+        }
+    }
+}
+
+fn distance_from_origin(point: Point) -> i64 {
+    point.0.abs() + point.1.abs()
+}
+
+/// One straight leg of the walk, covering the points `start + dir * t` for `t` in
+/// `0..=length`.
+struct Segment {
+    start: Point,
+    dir: (i64, i64),
+    length: i64,
+}
+
+fn cross(a: (i64, i64), b: (i64, i64)) -> i64 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+fn dot(a: (i64, i64), b: (i64, i64)) -> i64 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+impl Segment {
+    /// The smallest `t >= 1` at which this segment passes through a lattice point of `other`.
+    fn first_touch(&self, other: &Segment) -> Option<i64> {
+        let offset = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let det = cross(self.dir, other.dir);
+        let (low, high) = if det == 0 {
+            // Parallel segments only meet if they lie on the same line, and then they share a
+            // range of points.
+            if cross(offset, self.dir) != 0 {
+                return None;
+            }
+            let scale = dot(self.dir, self.dir);
+            let t0 = dot(offset, self.dir) / scale;
+            let t1 = t0 + dot(other.dir, self.dir) / scale * other.length;
+            (t0.min(t1), t0.max(t1))
+        } else {
+            // Otherwise solve `start + dir * t == other.start + other.dir * s`, where diagonal
+            // segments may cross between lattice points.
+            let (t, s) = (cross(offset, other.dir), cross(offset, self.dir));
+            if t % det != 0 || s % det != 0 || s / det < 0 || s / det > other.length {
+                return None;
+            }
+            (t / det, t / det)
+        };
+
+        let first = low.max(1);
+        if first <= high.min(self.length) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+}
+
+fn segments(instructions: &[Instruction]) -> Vec<Segment> {
+    let mut turtle = Turtle::new();
+    let mut segments = Vec::new();
+
+    for i in instructions.iter() {
+        let dist = turtle.orient(i);
+        let dir_vec = turtle.heading().offset();
+        let segment = Segment {
+            start: turtle.position(),
+            dir: (dir_vec.0 * dist.signum(), dir_vec.1 * dist.signum()),
+            length: dist.abs(),
+        };
+        turtle.walk(dist);
+        if segment.length > 0 {
+            segments.push(segment);
+        }
+    }
+
+    segments
+}
+
+/// The first point visited twice, found by intersecting each leg of the walk with the legs before
+/// it rather than visiting every point, so the running time only depends on the number of
+/// instructions. Gives the same result as the first of `Walk::revisits`.
+pub fn first_revisit(instructions: &[Instruction]) -> Option<Point> {
+    let segments = segments(instructions);
+    for (i, segment) in segments.iter().enumerate() {
+        // The start of a leg is the end of the previous one, so it has already been checked.
+        let first = segments[..i]
+            .iter()
+            .filter_map(|earlier| segment.first_touch(earlier))
+            .min();
+        if let Some(t) = first {
+            return Some((
+                segment.start.0 + segment.dir.0 * t,
+                segment.start.1 + segment.dir.1 * t,
+            ));
+        }
+    }
+    None
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, turtle::parse_instructions)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut turtle = Turtle::new();
+        for i in input.iter() {
+            turtle.execute(i);
+        }
+        Ok(Answer::from(distance_from_origin(turtle.position())))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let revisit = first_revisit(input)
+            .ok_or_else(|| Error::Invalid("no location is visited twice".to_string()))?;
+        Ok(Answer::from(distance_from_origin(revisit)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part1(input: &str) -> Answer {
+        Day01::part1(&Day01::parse(input).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_part1_examples() {
+        assert_eq!(part1("R2, L3"), Answer::Number(5));
+        assert_eq!(part1("R2, R2, R2"), Answer::Number(2));
+        assert_eq!(part1("R5, L5, R5, R3"), Answer::Number(12));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let input = Day01::parse("R8, R4, R4, R8").unwrap();
+        assert_eq!(Day01::part2(&input), Ok(Answer::Number(4)));
+
+        let input = Day01::parse("R2, L3").unwrap();
+        assert!(Day01::part2(&input).is_err());
+    }
+
+    #[test]
+    fn test_trace() {
+        let walk = Walk::trace(&Day01::parse("R8, R4, R4, R8").unwrap());
+        assert_eq!(walk.visited().count(), 25);
+        assert_eq!(walk.visited().nth(3), Some((3, (3, 0))));
+        assert_eq!(walk.end(), (4, -4));
+        assert_eq!(walk.revisits(), vec![(20, (4, 0))]);
+        assert_eq!(
+            walk.bounding_box(),
+            BoundingBox {
+                min: (0, -4),
+                max: (8, 4)
+            }
+        );
+
+        let walk = Walk::trace(&Day01::parse("R1, R1, R1, R1, R1").unwrap());
+        assert_eq!(walk.revisits(), vec![(4, (0, 0)), (5, (1, 0))]);
+    }
+
+    #[test]
+    fn test_first_revisit_agrees_with_walk() {
+        // A small linear congruential generator, so that the inputs are reproducible.
+        let mut seed: u32 = 12345;
+        let mut random = |n: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % n
+        };
+
+        for _i in 0..500 {
+            let count = 1 + random(12);
+            let instructions: Vec<String> = (0..count)
+                .map(|_i| match random(8) {
+                    0 => "D".to_string(),
+                    1 => format!("B{}", random(4)),
+                    2 => format!("F{}", random(7)),
+                    3..=5 => format!("L{}", random(7)),
+                    _ => format!("R{}", random(7)),
+                })
+                .collect();
+            let input = Day01::parse(&instructions.join(", ")).unwrap();
+
+            let expected = Walk::trace(&input)
+                .revisits()
+                .first()
+                .map(|&(_step, point)| point);
+            assert_eq!(first_revisit(&input), expected, "{:?}", instructions);
+        }
+    }
+
+    #[test]
+    fn test_first_revisit_with_long_distances() {
+        let input = Day01::parse("R2000000000, R5, R1000000000, R5, R7").unwrap();
+        assert_eq!(first_revisit(&input), Some((1_000_000_000, 0)));
+    }
+
+    #[test]
+    fn test_diagonal_crossings() {
+        // Diagonals that cross between lattice points do not count as a revisit.
+        let input = Day01::parse("D, R1, L0, L0, L1, L0, L0, L1").unwrap();
+        assert_eq!(Walk::trace(&input).end(), (1, 0));
+        assert_eq!(first_revisit(&input), None);
+
+        let input = Day01::parse("D, R1, L0, L0, L1, L0, L0, L1, L0, L0, L2").unwrap();
+        assert_eq!(first_revisit(&input), Some((1, -1)));
+    }
+
+    #[test]
+    fn test_render() {
+        let walk = Walk::trace(&Day01::parse("R8, R4, R4, R8").unwrap());
+        let expected = "....E....
+....#....
+....#....
+....#....
+S###X####
+....#...#
+....#...#
+....#...#
+....#####";
+        assert_eq!(walk.render(), expected);
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let svg = Walk::trace(&Day01::parse("R8, R4, R4, R8").unwrap()).to_svg();
+        assert!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -5 10 10\">")
+        );
+        assert!(svg.contains("points=\"0,0 8,0 8,4 4,4 4,-4\""));
+        assert!(svg.contains("class=\"start\" cx=\"0\" cy=\"0\""));
+        assert!(svg.contains("class=\"end\" cx=\"4\" cy=\"-4\""));
+        assert!(svg.contains("class=\"revisit\" cx=\"4\" cy=\"0\""));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::error::{Error, Result};
+use crate::grid::{Direction, Grid};
+use crate::parse::{complete, lines};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::character::complete::anychar;
+use nom::combinator::map_opt;
+use nom::multi::many1;
+use nom::IResult;
+
+const LAYOUT_PART1: &str = include_str!("../../data/keypads/square.txt");
+const START_PART1: char = '5';
+
+const LAYOUT_PART2: &str = include_str!("../../data/keypads/diamond.txt");
+const START_PART2: char = '5';
+
+/// A keypad of any shape, with `None` wherever there is no key.
+pub struct Keypad {
+    keys: Grid<Option<char>>,
+}
+
+/// The key reached at the end of one line of instructions, and the indices of the instructions in
+/// that line that were ignored because there was no key to move to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LineReport {
+    pub key: char,
+    pub blocked: Vec<usize>,
+}
+
+impl Keypad {
+    /// Reads a keypad layout with one row of keys per line, where a space marks a position
+    /// without a key.
+    pub fn parse(layout: &str) -> Result<Keypad> {
+        let rows: Vec<&str> = layout.lines().collect();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let mut keys: Vec<Option<char>> =
+                    row.chars().map(|c| Some(c).filter(|&c| c != ' ')).collect();
+                keys.resize(width, None);
+                keys
+            })
+            .collect();
+        let keys = Grid::from_rows(rows).unwrap();
+
+        let mut labels: Vec<char> = keys.positions().filter_map(|p| keys[p]).collect();
+        if labels.is_empty() {
+            return Err(Error::Invalid("keypad has no keys".to_string()));
+        }
+        labels.sort();
+        if let Some(pair) = labels.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(Error::Invalid(format!("key {} appears twice", pair[0])));
+        }
+
+        Ok(Keypad { keys })
+    }
+
+    /// The position of the key labelled `label`.
+    pub fn find(&self, label: char) -> Option<(usize, usize)> {
+        self.keys.positions().find(|&p| self.keys[p] == Some(label))
+    }
+
+    /// The position of the key next to `position` in `direction`, if there is one.
+    pub fn neighbour(
+        &self,
+        position: (usize, usize),
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        self.keys
+            .neighbour(position, direction)
+            .filter(|&next| self.keys[next].is_some())
+    }
+
+    fn key(&self, position: (usize, usize)) -> char {
+        self.keys[position].unwrap()
+    }
+}
+
+fn parse_direction(input: &str) -> IResult<&str, Direction> {
+    map_opt(anychar, Direction::from_letter)(input)
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(parse_direction)(input)
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<Vec<Direction>>> {
+    lines(parse_directions)(input)
+}
+
+/// Follows each line of `input` on `keypad`, starting on the key labelled `start`, and reports
+/// where each line ends up. Returns `None` if there is no key labelled `start`.
+pub fn follow_lines(
+    input: &[Vec<Direction>],
+    keypad: &Keypad,
+    start: char,
+) -> Option<Vec<LineReport>> {
+    let mut pos = keypad.find(start)?;
+    let mut reports = Vec::new();
+
+    for instruction_set in input.iter() {
+        let mut blocked = Vec::new();
+        for (i, &direction) in instruction_set.iter().enumerate() {
+            match keypad.neighbour(pos, direction) {
+                Some(next) => pos = next,
+                None => blocked.push(i),
+            }
+        }
+        reports.push(LineReport {
+            key: keypad.key(pos),
+            blocked,
+        });
+    }
+
+    Some(reports)
+}
+
+/// The code typed by following each line of `input` on `keypad`, starting on the key labelled
+/// `start`, or `None` if there is no such key.
+pub fn get_bathroom_code(input: &[Vec<Direction>], keypad: &Keypad, start: char) -> Option<String> {
+    let reports = follow_lines(input, keypad, start)?;
+    Some(reports.iter().map(|report| report.key).collect())
+}
+
+/// The shortest line of instructions that moves from the key at `from` to the key labelled `to`.
+/// Lines may not be empty, so staying on the same key takes at least one move.
+fn shortest_line(keypad: &Keypad, from: (usize, usize), to: char) -> Option<Vec<Direction>> {
+    let to = keypad.find(to)?;
+    // Each node remembers the move that reached it, so that blocked moves, which stay in place,
+    // can be told apart from the start.
+    let neighbours = |&(position, _last): &((usize, usize), Option<Direction>)| {
+        Direction::ALL.iter().map(move |&direction| {
+            let next = keypad.neighbour(position, direction).unwrap_or(position);
+            (next, Some(direction))
+        })
+    };
+    let is_goal =
+        |&(position, last): &((usize, usize), Option<Direction>)| position == to && last.is_some();
+    let path = search::shortest_path((from, None), neighbours, is_goal)?;
+    Some(path.iter().filter_map(|&(_position, last)| last).collect())
+}
+
+/// The shortest instructions, one line per key, that type `code` on `keypad` when starting on
+/// the key labelled `start`. Returns `None` if a key is missing or cannot be reached.
+pub fn instructions_for_code(
+    keypad: &Keypad,
+    start: char,
+    code: &str,
+) -> Option<Vec<Vec<Direction>>> {
+    let mut from = keypad.find(start)?;
+    let mut lines = Vec::new();
+    for key in code.chars() {
+        lines.push(shortest_line(keypad, from, key)?);
+        from = keypad.find(key)?;
+    }
+    Some(lines)
+}
+
+/// Writes instructions in the puzzle's input format.
+pub fn render_instructions(lines: &[Vec<Direction>]) -> String {
+    let lines: Vec<String> = lines
+        .iter()
+        .map(|line| line.iter().map(|d| d.letter()).collect())
+        .collect();
+    lines.join("\n")
+}
+
+fn solve(input: &[Vec<Direction>], layout: &str, start: char) -> Result<Answer> {
+    let keypad = Keypad::parse(layout)?;
+    let code = get_bathroom_code(input, &keypad, start)
+        .ok_or_else(|| Error::Invalid(format!("there is no key labelled {}", start)))?;
+    Ok(Answer::from(code))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<Direction>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, parse_lines)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        solve(input, LAYOUT_PART1, START_PART1)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        solve(input, LAYOUT_PART2, START_PART2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ULL\nRRDDD\nLURDL\nUUUUD\n";
+
+    #[test]
+    fn test_get_bathroom_code_examples() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), Ok(Answer::from("1985")));
+        assert_eq!(Day02::part2(&input), Ok(Answer::from("5DB3")));
+    }
+
+    #[test]
+    fn test_part2_starts_on_the_left_tip() {
+        let keypad = Keypad::parse(LAYOUT_PART2).unwrap();
+        assert_eq!(keypad.find(START_PART2), Some((0, 2)));
+        let input = Day02::parse("L\nU").unwrap();
+        assert_eq!(Day02::part2(&input), Ok(Answer::from("55")));
+    }
+
+    #[test]
+    fn test_custom_layout() {
+        let keypad = Keypad::parse("ab\n c\n").unwrap();
+        assert_eq!(keypad.find('a'), Some((0, 0)));
+        assert_eq!(keypad.find('c'), Some((1, 1)));
+        assert_eq!(keypad.find(' '), None);
+
+        let input = Day02::parse("DR\nLU\nRD").unwrap();
+        assert_eq!(
+            get_bathroom_code(&input, &keypad, 'a'),
+            Some("bac".to_string())
+        );
+        assert_eq!(get_bathroom_code(&input, &keypad, 'z'), None);
+    }
+
+    #[test]
+    fn test_follow_lines_reports_blocked_moves() {
+        let keypad = Keypad::parse(LAYOUT_PART1).unwrap();
+        let input = Day02::parse(EXAMPLE).unwrap();
+        let reports = follow_lines(&input, &keypad, '5').unwrap();
+        let blocked: Vec<&[usize]> = reports.iter().map(|r| &r.blocked[..]).collect();
+        assert_eq!(blocked, vec![&[2][..], &[4], &[], &[2, 3]]);
+        assert_eq!(reports[3].key, '5');
+    }
+
+    #[test]
+    fn test_keys_on_the_edge() {
+        // Keys touching the edge of the layout, with no padding around them.
+        let keypad = Keypad::parse("12\n3").unwrap();
+        assert_eq!(keypad.neighbour((0, 0), Direction::Up), None);
+        assert_eq!(keypad.neighbour((0, 0), Direction::Left), None);
+        assert_eq!(keypad.neighbour((0, 0), Direction::Right), Some((1, 0)));
+        assert_eq!(keypad.neighbour((1, 0), Direction::Down), None);
+        assert_eq!(keypad.neighbour((0, 1), Direction::Up), Some((0, 0)));
+
+        let input = Day02::parse("LLUURRRDD\nDLR").unwrap();
+        let reports = follow_lines(&input, &keypad, '3').unwrap();
+        assert_eq!(
+            reports,
+            vec![
+                LineReport {
+                    key: '2',
+                    blocked: vec![0, 1, 3, 5, 6, 7, 8]
+                },
+                LineReport {
+                    key: '2',
+                    blocked: vec![0]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_instructions_for_code() {
+        let keypad = Keypad::parse(LAYOUT_PART1).unwrap();
+        let lines = instructions_for_code(&keypad, '5', "1985").unwrap();
+        let lengths: Vec<usize> = lines.iter().map(|line| line.len()).collect();
+        assert_eq!(lengths, vec![2, 4, 1, 1]);
+
+        let lines = instructions_for_code(&keypad, '5', "55").unwrap();
+        assert_eq!(render_instructions(&lines), "UD\nUD");
+
+        assert_eq!(instructions_for_code(&keypad, '5', "5A"), None);
+        let keypad = Keypad::parse("1 2").unwrap();
+        assert_eq!(instructions_for_code(&keypad, '1', "2"), None);
+    }
+
+    #[test]
+    fn test_instructions_reproduce_code() {
+        let keypad = Keypad::parse(LAYOUT_PART2).unwrap();
+        for code in ["5DB3", "1D1D", "9", "77777", "ABCD"].iter() {
+            let lines = instructions_for_code(&keypad, START_PART2, code).unwrap();
+            let input = Day02::parse(&render_instructions(&lines)).unwrap();
+            assert_eq!(
+                get_bathroom_code(&input, &keypad, START_PART2).unwrap(),
+                *code
+            );
+        }
+
+        let keypad = Keypad::parse("1").unwrap();
+        let lines = instructions_for_code(&keypad, '1', "1").unwrap();
+        assert_eq!(render_instructions(&lines), "U");
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_layouts() {
+        assert!(Keypad::parse("  \n ").is_err());
+        assert!(Keypad::parse("121").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::error::{Error, Result};
+use crate::parse::{complete, parse_u32};
+use crate::solution::{Answer, Solution};
+use crate::table::{self, Table};
+
+const SIDES: usize = 3;
+
+fn is_valid_triangle(sides: &[u32]) -> bool {
+    let mut sides = sides.to_vec();
+    sides.sort_unstable();
+    sides[0] + sides[1] > sides[2]
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Table<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let table = Table::new(complete(input, table::parse_rows(parse_u32))?)?;
+        if table.columns() != SIDES {
+            return Err(Error::Invalid(format!(
+                "expected {} sides per row, found {}",
+                SIDES,
+                table.columns()
+            )));
+        }
+        Ok(table)
+    }
+
+    /// One triangle per row.
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let count = input.rows().iter().filter(|t| is_valid_triangle(t)).count();
+        Ok(Answer::from(count))
+    }
+
+    /// One triangle per column in each block of three rows.
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let count = input
+            .transposed(SIDES)?
+            .iter()
+            .filter(|t| is_valid_triangle(t))
+            .count();
+        Ok(Answer::from(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_triangle() {
+        assert!(!is_valid_triangle(&[5, 10, 25]));
+        assert!(is_valid_triangle(&[3, 4, 5]));
+    }
+
+    #[test]
+    fn test_reads_columns() {
+        let input = Day03::parse(
+            "101 301 501\n102 302 502\n103 303 503\n201 401 601\n202 402 602\n203 403 603",
+        )
+        .unwrap();
+        let by_columns = input.transposed(SIDES).unwrap();
+        assert_eq!(by_columns[0], vec![101, 102, 103]);
+        assert_eq!(by_columns[1], vec![301, 302, 303]);
+        assert_eq!(by_columns[5], vec![601, 602, 603]);
+        assert_eq!(Day03::part2(&input), Ok(Answer::Number(6)));
+    }
+
+    #[test]
+    fn test_ragged_tail_only_fails_part2() {
+        let input = Day03::parse("3 4 5\n5 10 25\n").unwrap();
+        assert_eq!(Day03::part1(&input), Ok(Answer::Number(1)));
+        assert!(Day03::part2(&input).is_err());
+        assert!(Day03::parse("1 2\n3 4\n5 6\n").is_err());
+    }
+}
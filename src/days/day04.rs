@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::parse::{complete, lines, parse_u32};
+use crate::solution::{Answer, Solution};
+
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::char,
+    combinator::recognize,
+    multi::separated_nonempty_list,
+    sequence::{delimited, terminated, tuple},
+    IResult,
+};
+
+pub struct Room {
+    encrypted_name: String,
+    sector_id: u32,
+    checksum: String,
+    character_counts: HashMap<char, u32>,
+}
+
+impl Room {
+    fn new(encrypted_name: &str, sector_id: u32, checksum: &str) -> Room {
+        let mut character_counts: HashMap<char, u32> = HashMap::new();
+        for c in encrypted_name.chars() {
+            if c == '-' {
+                continue;
+            }
+            let count = character_counts.entry(c).or_insert(0);
+            *count += 1;
+        }
+        Room {
+            encrypted_name: encrypted_name.to_string(),
+            sector_id,
+            checksum: checksum.to_string(),
+            character_counts,
+        }
+    }
+
+    fn checksum(&self) -> String {
+        let mut checksum: Vec<_> = self.character_counts.iter().collect();
+        checksum.sort_by(|(c1, i1), (c2, i2)| i1.cmp(i2).reverse().then(c1.cmp(c2)));
+        checksum.into_iter().map(|(c, _i)| c).take(5).collect()
+    }
+
+    fn is_real(&self) -> bool {
+        for c in self.checksum.chars() {
+            if !self.character_counts.contains_key(&c) {
+                return false;
+            }
+        }
+        self.checksum == self.checksum()
+    }
+
+    fn decrypt_char(&self, c: char) -> char {
+        let i = c as u8 - b'a';
+        let i = (i as u32 + self.sector_id) % 26;
+        (i as u8 + b'a') as char
+    }
+
+    fn decrypt(&self) -> String {
+        let mut s = String::new();
+        for c in self.encrypted_name.chars() {
+            s.push(match c {
+                '-' => ' ',
+                _ => self.decrypt_char(c),
+            });
+        }
+        s
+    }
+}
+
+fn parse_lowercase(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_lowercase())(input)
+}
+
+fn parse_checksum(input: &str) -> IResult<&str, &str> {
+    delimited(char('['), parse_lowercase, char(']'))(input)
+}
+
+fn parse_encrypted_name(input: &str) -> IResult<&str, &str> {
+    let name = recognize(separated_nonempty_list(char('-'), parse_lowercase));
+    terminated(name, char('-'))(input)
+}
+
+fn parse_room(input: &str) -> IResult<&str, Room> {
+    let parser = tuple((parse_encrypted_name, parse_u32, parse_checksum));
+    let (rest, (encrypted_name, sector_id, checksum)) = parser(input)?;
+    let room = Room::new(encrypted_name, sector_id, checksum);
+    Ok((rest, room))
+}
+
+fn parse_rooms(input: &str) -> IResult<&str, Vec<Room>> {
+    lines(parse_room)(input)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Room>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, parse_rooms)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let sum: u32 = input
+            .iter()
+            .filter(|r| r.is_real())
+            .map(|r| r.sector_id)
+            .sum();
+        Ok(Answer::from(sum))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let room = input
+            .iter()
+            .find(|r| r.decrypt() == "northpole object storage")
+            .ok_or_else(|| Error::Invalid("there is no North Pole storage room".to_string()))?;
+        Ok(Answer::from(room.sector_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(input: &str) -> Room {
+        complete(input, parse_room).unwrap()
+    }
+
+    #[test]
+    fn test_is_real() {
+        assert!(room("aaaaa-bbb-z-y-x-123[abxyz]").is_real());
+        assert!(room("a-b-c-d-e-f-g-h-987[abcde]").is_real());
+        assert!(room("not-a-real-room-404[oarel]").is_real());
+        assert!(!room("totally-real-room-200[decoy]").is_real());
+    }
+
+    #[test]
+    fn test_decrypt() {
+        assert_eq!(
+            room("qzmt-zixmtkozy-ivhz-343[zimth]").decrypt(),
+            "very encrypted name"
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_name() {
+        assert!(Day04::parse("-123[abcde]").is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::error::Result;
+use crate::hash::{self, hex_character, nibble, Hash};
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::alphanumeric1, combinator::map};
+
+use std::cell::RefCell;
+
+pub fn is_interesting(hash: &Hash) -> bool {
+    hash[0] == 0 && hash[1] == 0 && nibble(hash, 4) == 0
+}
+
+pub fn interesting_hashes(door_id: &str) -> impl Iterator<Item = Hash> + '_ {
+    (0..)
+        .map(move |i: u64| hash::md5(format!("{}{}", door_id, i)))
+        .filter(is_interesting)
+}
+
+/// A door ID together with the interesting hashes found for it so far, so that part 2 carries on
+/// from where part 1 stopped instead of hashing every index again.
+pub struct Door {
+    id: String,
+    found: RefCell<(Vec<Hash>, u64)>,
+}
+
+impl Door {
+    fn new(id: &str) -> Door {
+        Door {
+            id: id.to_string(),
+            found: RefCell::new((Vec::new(), 0)),
+        }
+    }
+
+    fn nth_interesting_hash(&self, n: usize) -> Hash {
+        let mut found = self.found.borrow_mut();
+        let (hashes, next_index) = &mut *found;
+        while hashes.len() <= n {
+            let hash = hash::md5(format!("{}{}", self.id, next_index));
+            *next_index += 1;
+            if is_interesting(&hash) {
+                hashes.push(hash);
+            }
+        }
+        hashes[n]
+    }
+
+    fn interesting_hashes(&self) -> impl Iterator<Item = Hash> + '_ {
+        (0..).map(move |n| self.nth_interesting_hash(n))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Door;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, map(alphanumeric1, Door::new))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let password: String = input
+            .interesting_hashes()
+            .take(8)
+            .map(|hash| hex_character(&hash, 5))
+            .collect();
+        Ok(Answer::from(password))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut password: Vec<Option<char>> = vec![None; 8];
+        let mut count = 0;
+
+        for hash in input.interesting_hashes() {
+            let pos = nibble(&hash, 5) as usize;
+            if pos <= 7 && password[pos].is_none() {
+                password[pos] = Some(hex_character(&hash, 6));
+                count += 1;
+                if count == 8 {
+                    break;
+                }
+            }
+        }
+
+        let password: String = password.iter().map(|c| c.unwrap()).collect();
+        Ok(Answer::from(password))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_interesting_hash_of_example() {
+        let hash = hash::md5("abc3231929");
+        assert!(is_interesting(&hash));
+        assert_eq!(hex_character(&hash, 5), '1');
+        assert_eq!(hex_character(&hash, 6), '5');
+        assert!(!is_interesting(&hash::md5("abc3231928")));
+    }
+
+    #[test]
+    fn test_door_reuses_found_hashes() {
+        let door = Door::new("abc");
+        door.found.borrow_mut().1 = 3231929;
+        assert_eq!(door.nth_interesting_hash(0), hash::md5("abc3231929"));
+        assert_eq!(door.found.borrow().1, 3231930);
+        assert_eq!(
+            door.interesting_hashes().next(),
+            Some(hash::md5("abc3231929"))
+        );
+        assert_eq!(door.found.borrow().1, 3231930);
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::parse::{complete, lines};
+use crate::solution::{Answer, Solution};
+
+use nom::character::complete::alpha1;
+use nom::combinator::map;
+use nom::IResult;
+
+fn parse_message(input: &str) -> IResult<&str, String> {
+    map(alpha1, String::from)(input)
+}
+
+fn parse_messages(input: &str) -> IResult<&str, Vec<String>> {
+    lines(parse_message)(input)
+}
+
+fn character_counts(input: &[String]) -> Vec<HashMap<char, u32>> {
+    let mut character_counts: Vec<HashMap<char, u32>> = Vec::new();
+
+    for _i in 0..input[0].len() {
+        character_counts.push(HashMap::new());
+    }
+
+    for message in input.iter() {
+        for (i, c) in message.chars().enumerate() {
+            let count = character_counts[i].entry(c).or_insert(0);
+            *count += 1;
+        }
+    }
+
+    character_counts
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let messages = complete(input, parse_messages)?;
+        if messages.iter().any(|m| m.len() != messages[0].len()) {
+            return Err(Error::Invalid("messages differ in length".to_string()));
+        }
+        Ok(messages)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut message = String::new();
+        for counts in character_counts(input).iter() {
+            let (c, _n) = counts.iter().max_by_key(|(_c, n)| *n).unwrap();
+            message.push(*c);
+        }
+        Ok(Answer::from(message))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut message = String::new();
+        for counts in character_counts(input).iter() {
+            let (c, _n) = counts.iter().min_by_key(|(_c, n)| *n).unwrap();
+            message.push(*c);
+        }
+        Ok(Answer::from(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "eedadn\ndrvtee\neandsr\nraavrd\natevrs\ntsrnev\nsdttsa\nrasrtv\n\
+                           nssdts\nntnada\nsvetve\ntesnvt\nvntsnd\nvrdear\ndvrsen\nenarar";
+
+    #[test]
+    fn test_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&input), Ok(Answer::from("easter")));
+        assert_eq!(Day06::part2(&input), Ok(Answer::from("advent")));
+    }
+}
@@ -0,0 +1,120 @@
+use crate::error::Result;
+use crate::parse::{complete, lines};
+use crate::solution::{Answer, Solution};
+
+use nom::{bytes::complete::take_while1, combinator::map, IResult};
+
+fn find_bab_outside(ip: &str, bab: &[char]) -> bool {
+    for s in ip.split(']') {
+        let s: Vec<char> = s.chars().collect();
+        for i in 0..s.len().saturating_sub(2) {
+            if s[i + 2] == '[' {
+                break;
+            }
+            if s[i] == bab[0] && s[i + 1] == bab[1] && s[i + 2] == bab[2] {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn supports_ssl(ip: &str) -> bool {
+    for s in ip.split('[').skip(1) {
+        let s: Vec<char> = s.chars().collect();
+        for i in 0..s.len().saturating_sub(2) {
+            if s[i + 2] == ']' {
+                break;
+            }
+            if s[i + 2] == s[i] {
+                let bab = vec![s[i + 1], s[i], s[i + 1]];
+                if find_bab_outside(ip, &bab) {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn abba_outside(ip: &str) -> bool {
+    for s in ip.split(']') {
+        let s: Vec<char> = s.chars().collect();
+        for i in 0..s.len().saturating_sub(3) {
+            if s[i + 3] == '[' {
+                break;
+            }
+            if s[i + 3] == s[i] && s[i + 2] == s[i + 1] && s[i] != s[i + 1] {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn abba_inside(ip: &str) -> bool {
+    for s in ip.split('[').skip(1) {
+        let s: Vec<char> = s.chars().collect();
+        for i in 0..s.len().saturating_sub(3) {
+            if s[i + 3] == ']' {
+                break;
+            }
+            if s[i + 3] == s[i] && s[i + 2] == s[i + 1] && s[i] != s[i + 1] {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+fn supports_tls(ip: &str) -> bool {
+    !abba_inside(ip) && abba_outside(ip)
+}
+
+fn parse_ip(input: &str) -> IResult<&str, String> {
+    let is_ip_char = |c: char| c.is_ascii_lowercase() || c == '[' || c == ']';
+    map(take_while1(is_ip_char), String::from)(input)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, lines(parse_ip))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(
+            input.iter().filter(|s| supports_tls(s)).count(),
+        ))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(
+            input.iter().filter(|s| supports_ssl(s)).count(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supports_tls() {
+        assert!(supports_tls("abba[mnop]qrst"));
+        assert!(!supports_tls("abcd[bddb]xyyx"));
+        assert!(!supports_tls("aaaa[qwer]tyui"));
+        assert!(supports_tls("ioxxoj[asdfgh]zxcvbn"));
+    }
+
+    #[test]
+    fn test_supports_ssl() {
+        assert!(supports_ssl("aba[bab]xyz"));
+        assert!(!supports_ssl("xyx[xyx]xyx"));
+        assert!(supports_ssl("aaa[kek]eke"));
+        assert!(supports_ssl("zazbz[bzb]cdb"));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::Result;
+use crate::parse::{complete, lines, parse_usize};
+use crate::solution::{Answer, Solution};
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::{map, verify};
+use nom::sequence::preceded;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+type Screen = [[bool; 50]; 6];
+
+#[derive(Debug)]
+pub enum Instruction {
+    Rect(usize, usize),
+    RotateRow(usize, usize),
+    RotateColumn(usize, usize),
+}
+
+impl Instruction {
+    fn execute(&self, mut screen: Screen) -> Screen {
+        match self {
+            Instruction::Rect(w, h) => {
+                for row in screen.iter_mut().take(*h) {
+                    for pixel in row.iter_mut().take(*w) {
+                        *pixel = true;
+                    }
+                }
+            }
+            Instruction::RotateRow(y, n) => {
+                let mut new_row = [false; 50];
+                for i in 0..50 {
+                    new_row[(i + *n % 50) % 50] = screen[*y][i];
+                }
+                screen[*y] = new_row;
+            }
+            Instruction::RotateColumn(x, n) => {
+                let mut new_col = [false; 6];
+                for i in 0..6 {
+                    new_col[(i + *n % 6) % 6] = screen[i][*x];
+                }
+                for i in 0..6 {
+                    screen[i][*x] = new_col[i];
+                }
+            }
+        }
+        screen
+    }
+}
+
+fn render_screen(screen: &Screen) -> String {
+    let rows: Vec<String> = screen
+        .iter()
+        .map(|row| row.iter().map(|b| if *b { '#' } else { '.' }).collect())
+        .collect();
+    rows.join("\n")
+}
+
+fn run(instructions: &[Instruction]) -> Screen {
+    let mut screen = [[false; 50]; 6];
+
+    for i in instructions.iter() {
+        screen = i.execute(screen);
+    }
+
+    screen
+}
+
+fn parse_rect(input: &str) -> IResult<&str, Instruction> {
+    let parser = separated_pair(parse_usize, char('x'), parse_usize);
+    let parser = preceded(tag("rect "), parser);
+    map(parser, |(a, b)| Instruction::Rect(a, b))(input)
+}
+
+fn parse_rotate_row(input: &str) -> IResult<&str, Instruction> {
+    let row = verify(parse_usize, |&y| y < 6);
+    let parser = separated_pair(row, tag(" by "), parse_usize);
+    let parser = preceded(tag("rotate row y="), parser);
+    map(parser, |(a, b)| Instruction::RotateRow(a, b))(input)
+}
+
+fn parse_rotate_column(input: &str) -> IResult<&str, Instruction> {
+    let column = verify(parse_usize, |&x| x < 50);
+    let parser = separated_pair(column, tag(" by "), parse_usize);
+    let parser = preceded(tag("rotate column x="), parser);
+    map(parser, |(a, b)| Instruction::RotateColumn(a, b))(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((parse_rect, parse_rotate_row, parse_rotate_column))(input)
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    lines(parse_instruction)(input)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, parse_instructions)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let f = |r: &[bool; 50]| r.iter().filter(|&b| *b).count();
+        let sum: usize = run(input).iter().map(f).sum();
+        Ok(Answer::from(sum))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(render_screen(&run(input))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "rect 3x2\nrotate column x=1 by 1\nrotate row y=0 by 4";
+        let screen = run(&Day08::parse(input).unwrap());
+        let rendered = render_screen(&screen);
+        let rows: Vec<&str> = rendered.lines().collect();
+        assert_eq!(&rows[0][..7], "....#.#");
+        assert_eq!(&rows[1][..7], "###....");
+        assert_eq!(&rows[2][..7], ".#.....");
+    }
+
+    #[test]
+    fn test_rotate_row_wraps() {
+        let screen = run(&Day08::parse("rect 1x1\nrotate row y=0 by 51").unwrap());
+        assert!(screen[0][1]);
+        assert!(!screen[0][0]);
+    }
+
+    #[test]
+    fn test_huge_rotations() {
+        let input = "rect 1x1\nrotate row y=0 by 18446744073709551615\nrotate column x=15 by 18446744073709551615";
+        let screen = run(&Day08::parse(input).unwrap());
+        assert!(screen[3][15]);
+        assert!(!screen[0][15]);
+    }
+}
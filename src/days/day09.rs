@@ -0,0 +1,139 @@
+use crate::error::{Error, Result};
+use crate::parse::{complete, parse_u64};
+use crate::solution::{Answer, Solution};
+
+use nom::{
+    branch::alt,
+    bytes::complete::take,
+    character::complete::{char, none_of},
+    combinator::{all_consuming, map, map_parser},
+    multi::{many0, many1},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+pub enum Token {
+    Char,
+    Marker(u64, u64, Vec<Token>),
+}
+
+impl Token {
+    fn len_part1(&self) -> Result<u64> {
+        match self {
+            Token::Char => Ok(1),
+            Token::Marker(l, r, _v) => l.checked_mul(*r).ok_or_else(too_long),
+        }
+    }
+
+    fn len_part2(&self) -> Result<u64> {
+        match self {
+            Token::Char => Ok(1),
+            Token::Marker(_l, r, v) => r.checked_mul(Self::vec_len_part2(v)?).ok_or_else(too_long),
+        }
+    }
+
+    fn vec_len_part1(v: &[Token]) -> Result<u64> {
+        v.iter().try_fold(0u64, |total, t| {
+            total.checked_add(t.len_part1()?).ok_or_else(too_long)
+        })
+    }
+
+    fn vec_len_part2(v: &[Token]) -> Result<u64> {
+        v.iter().try_fold(0u64, |total, t| {
+            total.checked_add(t.len_part2()?).ok_or_else(too_long)
+        })
+    }
+}
+
+fn too_long() -> Error {
+    Error::Invalid("the decompressed length does not fit in 64 bits".to_string())
+}
+
+fn parse_marker(input: &str) -> IResult<&str, Token> {
+    let parser = separated_pair(parse_u64, char('x'), parse_u64);
+    let (rest, (l, r)) = delimited(char('('), parser, char(')'))(input)?;
+    let (rest, tokens) = map_parser(take(l), all_consuming(many0(parse_token)))(rest)?;
+    Ok((rest, Token::Marker(l, r, tokens)))
+}
+
+fn parse_char(input: &str) -> IResult<&str, Token> {
+    map(none_of("(\n"), |_c| Token::Char)(input)
+}
+
+fn parse_token(input: &str) -> IResult<&str, Token> {
+    alt((parse_char, parse_marker))(input)
+}
+
+fn parse_tokens(input: &str) -> IResult<&str, Vec<Token>> {
+    many1(parse_token)(input)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Token>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, parse_tokens)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(Token::vec_len_part1(input)?))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(Token::vec_len_part2(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(input: &str) -> Vec<Token> {
+        Day09::parse(input).unwrap()
+    }
+
+    #[test]
+    fn test_vec_len_part1() {
+        assert_eq!(Token::vec_len_part1(&tokens("ADVENT")).unwrap(), 6);
+        assert_eq!(Token::vec_len_part1(&tokens("A(1x5)BC")).unwrap(), 7);
+        assert_eq!(Token::vec_len_part1(&tokens("(3x3)XYZ")).unwrap(), 9);
+        assert_eq!(
+            Token::vec_len_part1(&tokens("A(2x2)BCD(2x2)EFG")).unwrap(),
+            11
+        );
+        assert_eq!(Token::vec_len_part1(&tokens("(6x1)(1x3)A")).unwrap(), 6);
+        assert_eq!(
+            Token::vec_len_part1(&tokens("X(8x2)(3x3)ABCY")).unwrap(),
+            18
+        );
+    }
+
+    #[test]
+    fn test_vec_len_part2() {
+        assert_eq!(Token::vec_len_part2(&tokens("(3x3)XYZ")).unwrap(), 9);
+        assert_eq!(
+            Token::vec_len_part2(&tokens("X(8x2)(3x3)ABCY")).unwrap(),
+            20
+        );
+        assert_eq!(
+            Token::vec_len_part2(&tokens("(27x12)(20x12)(13x14)(7x10)(1x12)A")).unwrap(),
+            241920
+        );
+        assert_eq!(
+            Token::vec_len_part2(&tokens(
+                "(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN"
+            ))
+            .unwrap(),
+            445
+        );
+    }
+
+    #[test]
+    fn test_overflowing_length_is_an_error() {
+        let input = tokens("(1x18446744073709551615)AB");
+        assert!(Token::vec_len_part1(&input).is_err());
+        assert!(Token::vec_len_part2(&input).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+use regex::Regex;
+
+#[derive(Debug)]
+enum OutputType {
+    Bot(u32),
+    Output(u32),
+}
+
+#[derive(Debug)]
+struct Instruction {
+    bot_actor: u32,
+    output_low: OutputType,
+    output_high: OutputType,
+}
+
+pub struct Factory {
+    bot_values: HashMap<u32, Vec<u32>>,
+    bot_instructions: HashMap<u32, Instruction>,
+}
+
+/// The (low, high) pair each bot compared, and the value that ended up in each output bin.
+struct Outcome {
+    comparisons: HashMap<u32, (u32, u32)>,
+    outputs: HashMap<u32, u32>,
+}
+
+impl Factory {
+    fn run(&self) -> Result<Outcome> {
+        let mut bot_values = self.bot_values.clone();
+        let mut comparisons: HashMap<u32, (u32, u32)> = HashMap::new();
+        let mut outputs: HashMap<u32, u32> = HashMap::new();
+
+        loop {
+            let next_bot = bot_values.iter().find(|(_k, v)| v.len() > 1);
+            let (bot, values) = match next_bot {
+                Some(next_bot) => next_bot,
+                None => break,
+            };
+            if values.len() > 2 {
+                let message = format!("bot {} is given {} chips", bot, values.len());
+                return Err(Error::Invalid(message));
+            }
+
+            let low;
+            let high;
+
+            if values[0] < values[1] {
+                low = values[0];
+                high = values[1];
+            } else {
+                low = values[1];
+                high = values[0];
+            }
+
+            comparisons.insert(*bot, (low, high));
+
+            let instr = self.bot_instructions.get(bot).unwrap();
+            match instr.output_low {
+                OutputType::Bot(value) => {
+                    bot_values.entry(value).or_default().push(low);
+                }
+                OutputType::Output(value) => {
+                    outputs.insert(value, low);
+                }
+            }
+            match instr.output_high {
+                OutputType::Bot(value) => {
+                    bot_values.entry(value).or_default().push(high);
+                }
+                OutputType::Output(value) => {
+                    outputs.insert(value, high);
+                }
+            }
+            bot_values.insert(instr.bot_actor, Vec::new());
+        }
+
+        Ok(Outcome {
+            comparisons,
+            outputs,
+        })
+    }
+}
+
+fn parse_number(input: &str, rest: &str, number: &str) -> Result<u32> {
+    number
+        .parse()
+        .map_err(|_e| Error::parse_at(input, rest, format!("invalid number {}", number)))
+}
+
+fn parse_output_type(kind: &str, number: u32) -> OutputType {
+    match kind {
+        "bot" => OutputType::Bot(number),
+        "output" => OutputType::Output(number),
+        _ => unreachable!(),
+    }
+}
+
+fn parse_factory(input: &str) -> Result<Factory> {
+    let input_bin_regex = r"^value (\d+) goes to bot (\d+)$";
+    let instruction_regex =
+        r"^bot (\d+) gives low to (bot|output) (\d+) and high to (bot|output) (\d+)$";
+
+    let re_input_bin = Regex::new(input_bin_regex).unwrap();
+    let re_instruction = Regex::new(instruction_regex).unwrap();
+
+    let mut bot_values: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut bot_instructions: HashMap<u32, Instruction> = HashMap::new();
+    let mut recipients: Vec<u32> = Vec::new();
+
+    let mut offset = 0;
+    for line in input.trim_end_matches('\n').split('\n') {
+        let rest = &input[offset..];
+        offset += line.len() + 1;
+        if let Some(caps) = re_input_bin.captures(line) {
+            let value = parse_number(input, rest, &caps[1])?;
+            let bot = parse_number(input, rest, &caps[2])?;
+            bot_values.entry(bot).or_default().push(value);
+            recipients.push(bot);
+        } else if let Some(caps) = re_instruction.captures(line) {
+            let bot_actor = parse_number(input, rest, &caps[1])?;
+            let out_low = parse_number(input, rest, &caps[3])?;
+            let out_high = parse_number(input, rest, &caps[5])?;
+
+            let output_low = parse_output_type(&caps[2], out_low);
+            let output_high = parse_output_type(&caps[4], out_high);
+
+            for output in [&output_low, &output_high].iter() {
+                if let OutputType::Bot(bot) = output {
+                    recipients.push(*bot);
+                }
+            }
+
+            let instr = Instruction {
+                bot_actor,
+                output_low,
+                output_high,
+            };
+            bot_instructions.insert(bot_actor, instr);
+        } else {
+            return Err(Error::parse_at(
+                input,
+                rest,
+                format!("unrecognised instruction \"{}\"", line),
+            ));
+        }
+    }
+
+    for bot in recipients.iter() {
+        if !bot_instructions.contains_key(bot) {
+            return Err(Error::Invalid(format!("bot {} has no instructions", bot)));
+        }
+    }
+
+    Ok(Factory {
+        bot_values,
+        bot_instructions,
+    })
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Factory;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_factory(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let outcome = input.run()?;
+        let (bot, _values) = outcome
+            .comparisons
+            .iter()
+            .find(|(_bot, &values)| values == (17, 61))
+            .ok_or_else(|| Error::Invalid("no bot compares chips 17 and 61".to_string()))?;
+        Ok(Answer::from(*bot))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let outcome = input.run()?;
+        let mut answer: u32 = 1;
+        for bin in 0..3 {
+            match outcome.outputs.get(&bin) {
+                Some(value) => answer *= value,
+                None => return Err(Error::Invalid(format!("output {} stays empty", bin))),
+            }
+        }
+        Ok(Answer::from(answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "value 5 goes to bot 2
+bot 2 gives low to bot 1 and high to bot 0
+value 3 goes to bot 1
+bot 1 gives low to output 1 and high to bot 0
+bot 0 gives low to output 2 and high to output 0
+value 2 goes to bot 2";
+
+    #[test]
+    fn test_example() {
+        let outcome = Day10::parse(EXAMPLE).unwrap().run().unwrap();
+        assert_eq!(outcome.comparisons[&2], (2, 5));
+        assert_eq!(outcome.outputs[&0], 5);
+        assert_eq!(outcome.outputs[&1], 2);
+        assert_eq!(outcome.outputs[&2], 3);
+    }
+
+    #[test]
+    fn test_bot_given_three_chips() {
+        let input = "value 1 goes to bot 0\nvalue 2 goes to bot 0\nvalue 3 goes to bot 0
+bot 0 gives low to output 0 and high to output 1";
+        let factory = Day10::parse(input).unwrap();
+        assert!(factory.run().is_err());
+    }
+}
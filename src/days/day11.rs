@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::parse::{complete, lines};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::alpha1,
+    combinator::{map, value},
+    multi::separated_nonempty_list,
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+
+#[derive(Clone, Debug)]
+pub enum Item {
+    Generator(String),
+    MicroChip(String),
+    Elevator,
+}
+
+type Coords = Vec<usize>;
+
+#[derive(Debug)]
+struct ItemPlanner {
+    items: Vec<Item>,
+    coords: Coords,
+    matches: Vec<Option<usize>>,
+}
+
+impl ItemPlanner {
+    fn new(input: &[Vec<Item>]) -> ItemPlanner {
+        let mut coords = Vec::new();
+        let mut items = Vec::new();
+        let mut matches = Vec::new();
+        let mut element_seen = HashMap::new();
+        let mut index: usize = 0;
+
+        for (floor, floor_items) in input.iter().enumerate() {
+            for item in floor_items.iter() {
+                match item {
+                    Item::Elevator => {
+                        matches.push(None);
+                    }
+                    Item::Generator(e) | Item::MicroChip(e) => {
+                        if element_seen.contains_key(e) {
+                            let element_match: usize = *element_seen.get(e).unwrap();
+                            matches[element_match] = Some(index);
+                            matches.push(Some(element_match));
+                        } else {
+                            element_seen.insert(e, index);
+                            matches.push(None);
+                        }
+                    }
+                }
+                coords.push(floor);
+                items.push(item.clone());
+                index += 1;
+            }
+        }
+        ItemPlanner {
+            coords,
+            items,
+            matches,
+        }
+    }
+
+    fn floor_has_generator(&self, coords: &[usize], floor: usize) -> bool {
+        for (item_index, current_floor) in coords.iter().enumerate() {
+            if *current_floor == floor {
+                if let Item::Generator(_) = self.items[item_index] {
+                    if coords[item_index] == floor {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    fn coords_valid(&self, coords: &[usize]) -> bool {
+        for (item_index, current_item) in self.items.iter().enumerate() {
+            if let Item::MicroChip(_element) = current_item {
+                let current_floor = coords[item_index];
+                let other_item_index = self.matches[item_index].unwrap();
+                if coords[other_item_index] != current_floor
+                    && self.floor_has_generator(coords, current_floor)
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn get_elevator_index(&self) -> usize {
+        for (item_index, current_item) in self.items.iter().enumerate() {
+            if let Item::Elevator = current_item {
+                return item_index;
+            }
+        }
+        unreachable!()
+    }
+
+    fn get_valid_progressions_from(&self, coords: &[usize]) -> Vec<Coords> {
+        let mut progressions = Vec::new();
+        let mut floors = Vec::new();
+
+        let elevator = self.get_elevator_index();
+        let elevator_floor = coords[elevator];
+
+        if elevator_floor < 3 {
+            floors.push(elevator_floor + 1);
+        }
+        if elevator_floor > 0 {
+            floors.push(elevator_floor - 1);
+        }
+
+        for floor in floors.iter() {
+            let mut candidate = coords.to_owned();
+            candidate[elevator] = *floor;
+
+            for i in 0..self.items.len() {
+                if i != elevator && coords[i] == elevator_floor {
+                    candidate[i] = *floor;
+
+                    if self.coords_valid(&candidate) {
+                        progressions.push(candidate.clone());
+                    }
+
+                    for j in i + 1..self.items.len() {
+                        if j != elevator && coords[j] == elevator_floor {
+                            candidate[j] = *floor;
+                            if self.coords_valid(&candidate) {
+                                progressions.push(candidate.clone());
+                            }
+                            candidate[j] = elevator_floor;
+                        }
+                    }
+
+                    candidate[i] = elevator_floor;
+                }
+            }
+        }
+        progressions
+    }
+
+    /// Items of different elements are interchangeable, so every state is rewritten to the one
+    /// where the (generator, microchip) floor pairs are sorted. This keeps the search from
+    /// visiting equivalent states more than once.
+    fn normalise(&self, mut coords: Coords) -> Coords {
+        let generators: Vec<usize> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_i, item)| matches!(item, Item::Generator(_)))
+            .map(|(i, _item)| i)
+            .collect();
+
+        let mut pairs: Vec<(usize, usize)> = generators
+            .iter()
+            .map(|&g| (coords[g], coords[self.matches[g].unwrap()]))
+            .collect();
+        pairs.sort_unstable();
+
+        for (&g, (generator_floor, microchip_floor)) in generators.iter().zip(pairs) {
+            coords[g] = generator_floor;
+            coords[self.matches[g].unwrap()] = microchip_floor;
+        }
+        coords
+    }
+
+    /// Each step carries at most two items up one floor, so half the floors the items still have
+    /// to climb, rounded up, never overestimates the steps left.
+    fn steps_lower_bound(&self, coords: &[usize]) -> usize {
+        let elevator = self.get_elevator_index();
+        let remaining: usize = coords
+            .iter()
+            .enumerate()
+            .filter(|(item_index, _floor)| *item_index != elevator)
+            .map(|(_item_index, floor)| 3 - floor)
+            .sum();
+        remaining.div_ceil(2)
+    }
+
+    fn solve(&self) -> Result<usize> {
+        let neighbours = |coords: &Coords| {
+            self.get_valid_progressions_from(coords)
+                .into_iter()
+                .map(|candidate| self.normalise(candidate))
+        };
+        let is_done = |coords: &Coords| coords.iter().all(|&floor| floor == 3);
+        let start = self.normalise(self.coords.clone());
+
+        search::astar(
+            start,
+            neighbours,
+            |coords: &Coords| self.steps_lower_bound(coords),
+            is_done,
+        )
+        .ok_or_else(|| Error::Invalid("the items cannot all reach the fourth floor".to_string()))
+    }
+}
+
+fn parse_element(input: &str) -> IResult<&str, String> {
+    map(alpha1, String::from)(input)
+}
+
+fn parse_generator(input: &str) -> IResult<&str, Item> {
+    let parser = terminated(parse_element, tag(" generator"));
+    map(parser, Item::Generator)(input)
+}
+
+fn parse_microchip(input: &str) -> IResult<&str, Item> {
+    let parser = terminated(parse_element, tag("-compatible microchip"));
+    map(parser, Item::MicroChip)(input)
+}
+
+fn parse_item(input: &str) -> IResult<&str, Item> {
+    let article_parser = alt((tag("a "), tag("an ")));
+    let item_parser = alt((parse_generator, parse_microchip));
+    preceded(article_parser, item_parser)(input)
+}
+
+fn parse_non_empty_item_list(input: &str) -> IResult<&str, Vec<Item>> {
+    let separator = alt((tag(", and "), tag(", "), tag(" and ")));
+    separated_nonempty_list(separator, parse_item)(input)
+}
+
+fn parse_empty_item_list(input: &str) -> IResult<&str, Vec<Item>> {
+    value(Vec::new(), tag("nothing relevant"))(input)
+}
+
+fn parse_item_list(input: &str) -> IResult<&str, Vec<Item>> {
+    alt((parse_empty_item_list, parse_non_empty_item_list))(input)
+}
+
+fn parse_input_line(input: &str) -> IResult<&str, Vec<Item>> {
+    let prelude_parser = tuple((tag("The "), alpha1, tag(" floor contains ")));
+    delimited(prelude_parser, parse_item_list, tag("."))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<Item>>> {
+    lines(parse_input_line)(input)
+}
+
+fn validate(floors: &[Vec<Item>]) -> Result<()> {
+    if floors.len() != 4 {
+        let message = format!("expected 4 floors, found {}", floors.len());
+        return Err(Error::Invalid(message));
+    }
+
+    let mut counts: HashMap<&str, (usize, usize)> = HashMap::new();
+    for item in floors.iter().flatten() {
+        match item {
+            Item::Generator(e) => counts.entry(e).or_default().0 += 1,
+            Item::MicroChip(e) => counts.entry(e).or_default().1 += 1,
+            Item::Elevator => {}
+        }
+    }
+    for item in floors.iter().flatten() {
+        if let Item::Generator(e) | Item::MicroChip(e) = item {
+            if counts[e.as_str()] != (1, 1) {
+                let message = format!("expected one {} generator and one microchip", e);
+                return Err(Error::Invalid(message));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Vec<Item>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let floors = complete(input, parse_input)?;
+        validate(&floors)?;
+        Ok(floors)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let mut input = input.clone();
+        input[0].push(Item::Elevator);
+
+        Ok(Answer::from(ItemPlanner::new(&input).solve()?))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut input = input.clone();
+        input[0].push(Item::Elevator);
+        input[0].push(Item::MicroChip("elerium".to_string()));
+        input[0].push(Item::Generator("elerium".to_string()));
+        input[0].push(Item::MicroChip("dilithium".to_string()));
+        input[0].push(Item::Generator("dilithium".to_string()));
+
+        Ok(Answer::from(ItemPlanner::new(&input).solve()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+    #[test]
+    fn test_solve_example() {
+        let mut input = Day11::parse(EXAMPLE).unwrap();
+        input[0].push(Item::Elevator);
+        assert_eq!(ItemPlanner::new(&input).solve(), Ok(11));
+    }
+
+    #[test]
+    fn test_normalise_and_lower_bound() {
+        let mut input = Day11::parse(EXAMPLE).unwrap();
+        input[0].push(Item::Elevator);
+        let planner = ItemPlanner::new(&input);
+        // Items are hydrogen chip, lithium chip, elevator, hydrogen generator, lithium generator.
+        assert_eq!(planner.normalise(vec![0, 0, 0, 1, 2]), vec![0, 0, 0, 1, 2]);
+        assert_eq!(planner.normalise(vec![0, 0, 0, 2, 1]), vec![0, 0, 0, 1, 2]);
+        assert_eq!(planner.steps_lower_bound(&[0, 0, 0, 1, 2]), 5);
+        assert_eq!(planner.steps_lower_bound(&[3, 3, 3, 3, 3]), 0);
+    }
+
+    #[test]
+    fn test_parse_item_list_separators() {
+        let names = |list: &str| -> Vec<String> {
+            let (rest, items) = parse_item_list(list).unwrap();
+            assert_eq!(rest, "");
+            items
+                .iter()
+                .map(|item| match item {
+                    Item::Generator(e) => format!("{} generator", e),
+                    Item::MicroChip(e) => format!("{} microchip", e),
+                    Item::Elevator => "elevator".to_string(),
+                })
+                .collect()
+        };
+
+        assert_eq!(names("a thulium generator"), vec!["thulium generator"]);
+        assert_eq!(
+            names("a thulium generator and a thulium-compatible microchip"),
+            vec!["thulium generator", "thulium microchip"]
+        );
+        assert_eq!(
+            names("a thulium generator, a plutonium generator, and a strontium generator"),
+            vec![
+                "thulium generator",
+                "plutonium generator",
+                "strontium generator"
+            ]
+        );
+    }
+}
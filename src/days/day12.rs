@@ -0,0 +1,47 @@
+use crate::assembunny::{self, Instruction, Machine, Register};
+use crate::error::Result;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+fn run_with_c(program: &[Instruction], c: i64) -> i64 {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_register(Register::C, c);
+    machine.run();
+    machine.register(Register::A)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, assembunny::parse_program)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(run_with_c(input, 0)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(run_with_c(input, 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Day12::parse("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a\n").unwrap();
+        assert_eq!(Day12::part1(&input), Ok(Answer::Number(42)));
+    }
+
+    #[test]
+    fn test_part2_initialises_c() {
+        let input = Day12::parse("cpy c a").unwrap();
+        assert_eq!(Day12::part1(&input), Ok(Answer::Number(0)));
+        assert_eq!(Day12::part2(&input), Ok(Answer::Number(1)));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::error::{Error, Result};
+use crate::parse::{complete, parse_u64};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::combinator::verify;
+
+const DESTINATION: (u64, u64) = (31, 39);
+const MAX_DISTANCE: usize = 50;
+/// The maze is infinite, so the search for the destination gives up beyond this many steps.
+const SEARCH_LIMIT: usize = 1000;
+/// Keeps `is_open_space` from overflowing anywhere the searches can reach.
+const MAX_FAVORITE_NUMBER: u64 = u32::MAX as u64;
+
+fn get_possible_coords(&(x, y): &(u64, u64)) -> Vec<(u64, u64)> {
+    let mut result = Vec::new();
+    if x > 0 {
+        result.push((x - 1, y));
+    }
+    result.push((x + 1, y));
+
+    if y > 0 {
+        result.push((x, y - 1));
+    }
+    result.push((x, y + 1));
+
+    result
+}
+
+fn is_open_space(x: u64, y: u64, favorite_number: u64) -> bool {
+    let sum = x * (x + 3) + y * (2 * x + y + 1) + favorite_number;
+    sum.count_ones().is_multiple_of(2)
+}
+
+fn maze(favorite_number: u64) -> impl Iterator<Item = ((u64, u64), usize)> {
+    let neighbours = move |coords: &(u64, u64)| {
+        get_possible_coords(coords)
+            .into_iter()
+            .filter(move |&(x, y)| is_open_space(x, y, favorite_number))
+    };
+    search::bfs((1, 1), neighbours)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, verify(parse_u64, |&n| n <= MAX_FAVORITE_NUMBER))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let (_dest, distance) = maze(*input)
+            .take_while(|&(_coords, distance)| distance <= SEARCH_LIMIT)
+            .find(|&(coords, _distance)| coords == DESTINATION)
+            .ok_or_else(|| {
+                let message = format!("no path to {:?} within {} steps", DESTINATION, SEARCH_LIMIT);
+                Error::Invalid(message)
+            })?;
+        Ok(Answer::from(distance))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let count = maze(*input)
+            .take_while(|&(_coords, distance)| distance <= MAX_DISTANCE)
+            .count();
+        Ok(Answer::from(count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_open_space() {
+        let rows = [".#.####.##", "..#..#...#", "#....##...", "###.#.###."];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                assert_eq!(is_open_space(x as u64, y as u64, 10), c == '.');
+            }
+        }
+    }
+
+    #[test]
+    fn test_shortest_path_example() {
+        let (_dest, distance) = maze(10).find(|&(coords, _d)| coords == (7, 4)).unwrap();
+        assert_eq!(distance, 11);
+    }
+
+    #[test]
+    fn test_parse_rejects_huge_favorite_number() {
+        assert_eq!(Day13::parse("4294967295\n").unwrap(), 4294967295);
+        assert!(Day13::parse("18446744073709551615\n").is_err());
+    }
+}
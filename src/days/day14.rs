@@ -1,30 +1,32 @@
 use std::collections::HashMap;
-use std::io;
-use std::io::prelude::*;
 
-struct Stream<'a> {
+use crate::error::Result;
+use crate::hash;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::alphanumeric1, combinator::map};
+
+pub struct Stream<'a> {
     salt: &'a str,
     memo: HashMap<String, String>,
     memo_quintuplets: HashMap<(u64, u8), bool>,
-    memo_triplets: HashMap<u64, Option<u8>>
+    memo_triplets: HashMap<u64, Option<u8>>,
 }
 
 impl<'a> Stream<'a> {
-    fn new(salt: &'a str) -> Self {
+    pub fn new(salt: &'a str) -> Self {
         Stream {
             salt,
             memo: HashMap::new(),
             memo_quintuplets: HashMap::new(),
-            memo_triplets: HashMap::new()
+            memo_triplets: HashMap::new(),
         }
     }
 
     fn get_hash_stretched_memoized(&mut self, input: String) -> String {
         if !self.memo.contains_key(&input) {
-            let mut hash: String = String::from(format!("{:x}", md5::compute(input.clone())));
-            for _i in 0..2016 {
-                hash = String::from(format!("{:x}", md5::compute(hash)));
-            }
+            let hash = hash::md5_hex_stretched(&input, 2016);
             self.memo.insert(input.to_string(), hash);
         }
         self.memo.get(&input).unwrap().to_string()
@@ -32,29 +34,27 @@ impl<'a> Stream<'a> {
 
     fn get_hash_stretched(&mut self, n: u64) -> Vec<u8> {
         let input = format!("{}{}", self.salt, n);
-        let hash: String = self.get_hash_stretched_memoized(input);
-        String::from(hash).into_bytes()
+        self.get_hash_stretched_memoized(input).into_bytes()
     }
 
     fn get_hash(&self, n: u64) -> Vec<u8> {
         let input = format!("{}{}", self.salt, n);
-        let hash = format!("{:x}", md5::compute(input));
-        String::from(hash).into_bytes()
+        hash::md5_hex(input).into_bytes()
     }
 
-    fn contains_same_sequence_specified(hash: &Vec<u8>, length: usize, character: u8) -> bool {
+    fn contains_same_sequence_specified(hash: &[u8], length: usize, character: u8) -> bool {
         'outerloop: for i in 0..hash.len() - length + 1 {
             for j in 0..length {
                 if hash[i + j] != character {
                     continue 'outerloop;
-                } 
+                }
             }
             return true;
         }
         false
     }
 
-    fn contains_same_sequence(hash: &Vec<u8>, length: usize) -> Option<u8> {
+    fn contains_same_sequence(hash: &[u8], length: usize) -> Option<u8> {
         'outerloop: for i in 0..hash.len() - length + 1 {
             let character = hash[i];
             for j in 1..length {
@@ -67,7 +67,7 @@ impl<'a> Stream<'a> {
         None
     }
 
-    fn is_key_part_one(&self, hash_index: u64) -> bool {
+    pub fn is_key_part_one(&self, hash_index: u64) -> bool {
         let hash = self.get_hash(hash_index);
         if let Some(c) = Self::contains_same_sequence(&hash, 3) {
             for next_index in hash_index + 1..=hash_index + 1000 {
@@ -86,7 +86,8 @@ impl<'a> Stream<'a> {
         } else {
             let hash = self.get_hash_stretched(index);
             let contains_quintuplet = Self::contains_same_sequence_specified(&hash, 5, character);
-            self.memo_quintuplets.insert((index, character), contains_quintuplet);
+            self.memo_quintuplets
+                .insert((index, character), contains_quintuplet);
             contains_quintuplet
         }
     }
@@ -102,7 +103,7 @@ impl<'a> Stream<'a> {
         }
     }
 
-    fn is_key_part_two(&mut self, hash_index: u64) -> bool {
+    pub fn is_key_part_two(&mut self, hash_index: u64) -> bool {
         if let Some(c) = self.contains_triplet(hash_index) {
             for next_index in hash_index + 1..=hash_index + 1000 {
                 if self.contains_quintuplet(next_index, c) {
@@ -114,49 +115,57 @@ impl<'a> Stream<'a> {
     }
 }
 
-fn main() -> io::Result<()> {
-    let mut input = String::new();
-    io::stdin().lock().read_to_string(&mut input).unwrap();
-    let input = &input[..];
+pub struct Day14;
 
-    let mut stream = Stream::new(input);
+impl Solution for Day14 {
+    type Input = String;
 
-    let mut part1_done = false;
-    let mut count_part1 = 0;
-    let mut index_part1 = 0;
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, map(alphanumeric1, String::from))
+    }
 
-    let mut part2_done = false;
-    let mut count_part2 = 0;
-    let mut index_part2 = 0;
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let stream = Stream::new(input);
+        let index = (0..)
+            .filter(|&index| stream.is_key_part_one(index))
+            .nth(63)
+            .unwrap();
+        Ok(Answer::from(index))
+    }
 
-    let mut index = 0;
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut stream = Stream::new(input);
+        let index = (0..)
+            .filter(|&index| stream.is_key_part_two(index))
+            .nth(63)
+            .unwrap();
+        Ok(Answer::from(index))
+    }
+}
 
-    loop {
-        if !part1_done && stream.is_key_part_one(index) {
-            count_part1 += 1;
-            if count_part1 == 64 {
-                part1_done = true;
-                index_part1 = index;
-                if part2_done {
-                    break;
-                }
-            }
-        }
-        if !part2_done && stream.is_key_part_two(index) {
-            count_part2 += 1;
-            if count_part2 == 64 {
-                part2_done = true;
-                index_part2 = index;
-                if part1_done {
-                    break;
-                }
-            }
-        }
-        index += 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_key_part_one() {
+        let stream = Stream::new("abc");
+        assert!(!stream.is_key_part_one(18));
+        assert!(stream.is_key_part_one(39));
+        assert!(stream.is_key_part_one(92));
     }
 
-    println!("The index of the 64th key is: {}", index_part1);
-    println!("The index of the 64th stretched key is: {}", index_part2);
+    #[test]
+    fn test_is_key_part_two() {
+        let mut stream = Stream::new("abc");
+        assert!(!stream.is_key_part_two(5));
+        assert!(stream.is_key_part_two(10));
+    }
 
-    Ok(())
+    #[test]
+    fn test_get_hash_stretched() {
+        let mut stream = Stream::new("abc");
+        let hash = stream.get_hash_stretched(0);
+        assert_eq!(hash, b"a107ff634856bb300138cac6568c0f24".to_vec());
+    }
 }
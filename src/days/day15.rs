@@ -0,0 +1,143 @@
+use crate::error::{Error, Result};
+use crate::parse::{complete, lines, parse_u32};
+use crate::solution::{Answer, Solution};
+
+use nom::{
+    bytes::complete::tag,
+    combinator::verify,
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+#[derive(Clone, Debug)]
+pub struct Disc {
+    positions: u32,
+    start_position: u32,
+}
+
+fn parse_id(input: &str) -> IResult<&str, u32> {
+    preceded(tag("Disc #"), parse_u32)(input)
+}
+
+fn parse_positions(input: &str) -> IResult<&str, u32> {
+    preceded(tag(" has "), verify(parse_u32, |&positions| positions > 0))(input)
+}
+
+fn parse_start_position(input: &str) -> IResult<&str, u32> {
+    delimited(
+        tag(" positions; at time=0, it is at position "),
+        parse_u32,
+        tag("."),
+    )(input)
+}
+
+fn parse_discs(input: &str) -> IResult<&str, Vec<Disc>> {
+    lines(parse_disc)(input)
+}
+
+fn parse_disc(input: &str) -> IResult<&str, Disc> {
+    let (rest, (_id, positions, start_position)) =
+        tuple((parse_id, parse_positions, parse_start_position))(input)?;
+    Ok((
+        rest,
+        Disc {
+            positions,
+            start_position,
+        },
+    ))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The discs are back where they started after the least common multiple of their sizes, so if
+/// the capsule cannot get through before then it never will.
+fn timestamp_till_first_capsule(discs: &[Disc]) -> Option<u64> {
+    let period = discs.iter().fold(1, |period, disc| {
+        let positions = u64::from(disc.positions);
+        period / gcd(period, positions) * positions
+    });
+    (0..period).find(|&time| can_get_capsule(time, discs))
+}
+
+fn can_get_capsule(time: u64, discs: &[Disc]) -> bool {
+    for (i, disc) in discs.iter().enumerate() {
+        let position = u64::from(disc.start_position) + time + i as u64 + 1;
+        if !position.is_multiple_of(u64::from(disc.positions)) {
+            return false;
+        }
+    }
+    true
+}
+
+fn solve(discs: &[Disc]) -> Result<Answer> {
+    let time = timestamp_till_first_capsule(discs)
+        .ok_or_else(|| Error::Invalid("the discs never line up".to_string()))?;
+    Ok(Answer::from(time))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Disc>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let discs = complete(input, parse_discs)?;
+        for (i, disc) in discs.iter().enumerate() {
+            if disc.start_position >= disc.positions {
+                return Err(Error::Invalid(format!(
+                    "disc {} starts at position {} but only has {} positions",
+                    i + 1,
+                    disc.start_position,
+                    disc.positions
+                )));
+            }
+        }
+        Ok(discs)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let mut input = input.clone();
+        input.push(Disc {
+            positions: 11,
+            start_position: 0,
+        });
+        solve(&input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_till_first_capsule() {
+        let input = "Disc #1 has 5 positions; at time=0, it is at position 4.\n\
+                     Disc #2 has 2 positions; at time=0, it is at position 1.";
+        let discs = Day15::parse(input).unwrap();
+        assert_eq!(timestamp_till_first_capsule(&discs), Some(5));
+    }
+
+    #[test]
+    fn test_discs_that_never_line_up() {
+        let input = "Disc #1 has 2 positions; at time=0, it is at position 0.\n\
+                     Disc #2 has 2 positions; at time=0, it is at position 0.";
+        let discs = Day15::parse(input).unwrap();
+        assert_eq!(timestamp_till_first_capsule(&discs), None);
+    }
+
+    #[test]
+    fn test_parse_rejects_start_beyond_disc() {
+        let input = "Disc #1 has 5 positions; at time=0, it is at position 5.";
+        assert!(Day15::parse(input).is_err());
+    }
+}
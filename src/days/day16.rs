@@ -0,0 +1,90 @@
+use crate::error::Result;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::one_of, multi::many1};
+
+fn iterate(mut data: Vec<char>) -> Vec<char> {
+    let len = data.len();
+    data.push('0');
+    for i in 0..len {
+        let character = data[len - 1 - i];
+        data.push(if character == '0' { '1' } else { '0' });
+    }
+    data
+}
+
+fn checksum_iteration(data: &[char]) -> Vec<char> {
+    data.chunks_exact(2)
+        .map(|pair| if pair[0] == pair[1] { '1' } else { '0' })
+        .collect()
+}
+
+/// Halves `data` until its length is odd, so data that already has an odd length is its own
+/// checksum.
+fn checksum(data: &[char]) -> Vec<char> {
+    let mut checksum = data.to_vec();
+    while !checksum.is_empty() && checksum.len().is_multiple_of(2) {
+        checksum = checksum_iteration(&checksum);
+    }
+    checksum
+}
+
+pub fn checksum_for_disk(data: &[char], disk_size: usize) -> String {
+    let mut data = data.to_vec();
+    while data.len() < disk_size {
+        data = iterate(data);
+    }
+    let checksum = checksum(&data[..disk_size]);
+    checksum.into_iter().collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, many1(one_of("01")))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(checksum_for_disk(input, 272)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(checksum_for_disk(input, 35651584)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn test_iterate() {
+        assert_eq!(iterate(chars("1")), chars("100"));
+        assert_eq!(iterate(chars("0")), chars("001"));
+        assert_eq!(iterate(chars("11111")), chars("11111000000"));
+        assert_eq!(
+            iterate(chars("111100001010")),
+            chars("1111000010100101011110000")
+        );
+    }
+
+    #[test]
+    fn test_checksum_for_disk() {
+        assert_eq!(checksum(&chars("110010110100")), chars("100"));
+        assert_eq!(checksum_for_disk(&chars("10000"), 20), "01100");
+    }
+
+    #[test]
+    fn test_checksum_of_odd_length() {
+        assert_eq!(checksum_iteration(&chars("110")), chars("1"));
+        assert_eq!(checksum_for_disk(&chars("10000"), 5), "10000");
+    }
+}
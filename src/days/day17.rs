@@ -0,0 +1,120 @@
+use crate::error::{Error, Result};
+use crate::grid::Direction;
+use crate::hash::{self, nibble};
+use crate::parse::complete;
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::alphanumeric1, combinator::map};
+
+const SIZE: i32 = 4;
+
+#[derive(Clone, Debug)]
+struct State {
+    x: i32,
+    y: i32,
+    path: String,
+}
+
+impl State {
+    fn is_at_vault(&self) -> bool {
+        self.x == SIZE - 1 && self.y == SIZE - 1
+    }
+
+    fn next_states(&self, passcode: &str) -> Vec<State> {
+        if self.is_at_vault() {
+            return Vec::new();
+        }
+
+        let hash = hash::md5(format!("{}{}", passcode, self.path));
+        let mut result = Vec::new();
+        // The first four hash nibbles open the doors in the same order as `Direction::ALL`.
+        for (i, &door) in Direction::ALL.iter().enumerate() {
+            let (dx, dy) = door.offset();
+            let (x, y) = (self.x + dx, self.y + dy);
+            let in_bounds = (0..SIZE).contains(&x) && (0..SIZE).contains(&y);
+            if in_bounds && nibble(&hash, i) >= 0xb {
+                let mut path = self.path.clone();
+                path.push(door.letter());
+                result.push(State { x, y, path });
+            }
+        }
+        result
+    }
+}
+
+/// Every path that ends at the vault, shortest first. Each state carries the path taken to reach
+/// it, so no state can be reached twice and the search needs no record of visited states.
+fn paths_to_vault(passcode: &str) -> impl Iterator<Item = String> + '_ {
+    let start = State {
+        x: 0,
+        y: 0,
+        path: String::new(),
+    };
+    search::bfs_tree(start, move |state: &State| state.next_states(passcode))
+        .filter(|(state, _distance)| state.is_at_vault())
+        .map(|(state, _distance)| state.path)
+}
+
+fn no_path() -> Error {
+    Error::Invalid("no path reaches the vault".to_string())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, map(alphanumeric1, String::from))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let path = paths_to_vault(input).next().ok_or_else(no_path)?;
+        Ok(Answer::from(path))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let path = paths_to_vault(input).last().ok_or_else(no_path)?;
+        Ok(Answer::from(path.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_states() {
+        let start = State {
+            x: 0,
+            y: 0,
+            path: String::new(),
+        };
+        let next = start.next_states("hijkl");
+        assert_eq!(next.len(), 1);
+        assert_eq!(next[0].path, "D");
+        assert!(next[0].next_states("hijkl").iter().all(|s| s.path != "DD"));
+        assert_eq!(paths_to_vault("hijkl").next(), None);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        assert_eq!(paths_to_vault("ihgpwlah").next().unwrap(), "DDRRRD");
+        assert_eq!(paths_to_vault("kglvqrro").next().unwrap(), "DDUDRLRRUDRD");
+        assert_eq!(
+            paths_to_vault("ulqzkmiv").next().unwrap(),
+            "DRURDRUDDLLDLUURRDULRLDUUDDDRR"
+        );
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let input = Day17::parse("ihgpwlah").unwrap();
+        assert_eq!(Day17::part2(&input), Ok(Answer::Number(370)));
+        let input = Day17::parse("kglvqrro").unwrap();
+        assert_eq!(Day17::part2(&input), Ok(Answer::Number(492)));
+        let input = Day17::parse("ulqzkmiv").unwrap();
+        assert_eq!(Day17::part2(&input), Ok(Answer::Number(830)));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::error::Result;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::one_of, multi::many1};
+
+const ROWS_PART1: usize = 40;
+const ROWS_PART2: usize = 400_000;
+const WORD_BITS: usize = 64;
+
+/// A row of tiles stored as a bitset of 64-bit words, where bit `i` is set if tile `i` is a trap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row {
+    traps: Vec<u64>,
+    width: usize,
+}
+
+impl Row {
+    fn from_tiles(tiles: &[char]) -> Row {
+        let traps = tiles
+            .chunks(WORD_BITS)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_i, &c)| c == '^')
+                    .fold(0, |word, (i, _c)| word | (1 << i))
+            })
+            .collect();
+        Row {
+            traps,
+            width: tiles.len(),
+        }
+    }
+
+    /// The bits of the last word that hold tiles.
+    fn last_mask(&self) -> u64 {
+        match self.width % WORD_BITS {
+            0 => !0,
+            used => (1 << used) - 1,
+        }
+    }
+
+    fn is_trap(&self, i: usize) -> bool {
+        self.traps[i / WORD_BITS] & (1 << (i % WORD_BITS)) != 0
+    }
+
+    /// A tile is a trap exactly when one of its left and right neighbours is a trap, so the next
+    /// row is the XOR of this row shifted in both directions, with bits carried between words.
+    fn next(&self) -> Row {
+        let words = &self.traps;
+        let mut traps: Vec<u64> = (0..words.len())
+            .map(|i| {
+                let from_left = (words[i] << 1) | i.checked_sub(1).map_or(0, |j| words[j] >> 63);
+                let from_right = (words[i] >> 1) | words.get(i + 1).map_or(0, |w| w << 63);
+                from_left ^ from_right
+            })
+            .collect();
+        if let Some(last) = traps.last_mut() {
+            *last &= self.last_mask();
+        }
+        Row {
+            traps,
+            width: self.width,
+        }
+    }
+
+    fn count_safe(&self) -> usize {
+        let traps: u32 = self.traps.iter().map(|w| w.count_ones()).sum();
+        self.width - traps as usize
+    }
+
+    fn render(&self) -> String {
+        (0..self.width)
+            .map(|i| if self.is_trap(i) { '^' } else { '.' })
+            .collect()
+    }
+
+    fn rows(&self) -> impl Iterator<Item = Row> {
+        std::iter::successors(Some(self.clone()), |row| Some(row.next()))
+    }
+}
+
+pub fn count_safe(first: &Row, rows: usize) -> usize {
+    first.rows().take(rows).map(|row| row.count_safe()).sum()
+}
+
+/// Renders the first `rows` rows of the room, one row per line.
+pub fn render(first: &Row, rows: usize) -> String {
+    let rendered: Vec<String> = first.rows().take(rows).map(|row| row.render()).collect();
+    rendered.join("\n")
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Row;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let tiles = complete(input, many1(one_of(".^")))?;
+        Ok(Row::from_tiles(&tiles))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(count_safe(input, ROWS_PART1)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(count_safe(input, ROWS_PART2)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let row = Day18::parse("..^^.").unwrap();
+        assert_eq!(render(&row, 3), "..^^.\n.^^^^\n^^..^");
+    }
+
+    #[test]
+    fn test_count_safe() {
+        let row = Day18::parse(".^^.^.^^^^").unwrap();
+        assert_eq!(count_safe(&row, 10), 38);
+        let rendered = render(&row, 10);
+        assert_eq!(rendered.lines().last().unwrap(), "^^.^^^..^^");
+    }
+
+    #[test]
+    fn test_wide_rows() {
+        // Traps on both sides of the boundary between the first two words, and on the last tile.
+        let mut tiles = vec!['.'; 130];
+        tiles[63] = '^';
+        tiles[129] = '^';
+        let row = Row::from_tiles(&tiles);
+
+        let mut expected = vec!['.'; 130];
+        expected[62] = '^';
+        expected[64] = '^';
+        expected[128] = '^';
+        let expected: String = expected.into_iter().collect();
+        assert_eq!(row.next().render(), expected);
+        assert_eq!(count_safe(&row, 2), 128 + 127);
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::VecDeque;
+
+use crate::error::{Error, Result};
+use crate::parse::{complete, parse_usize};
+use crate::solution::{Answer, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Simulation,
+    ClosedForm,
+}
+
+/// The elf left holding all the presents when every elf steals from the elf to their left.
+pub fn winner_next_door(elves: usize, mode: Mode) -> usize {
+    match mode {
+        Mode::Simulation => {
+            let mut circle: VecDeque<usize> = (1..=elves).collect();
+            while circle.len() > 1 {
+                circle.rotate_left(1);
+                circle.pop_front();
+            }
+            circle[0]
+        }
+        Mode::ClosedForm => {
+            let power_of_two = 1 << (usize::BITS - 1 - elves.leading_zeros());
+            2 * (elves - power_of_two) + 1
+        }
+    }
+}
+
+/// The elf left holding all the presents when every elf steals from the elf directly across the
+/// circle.
+pub fn winner_across(elves: usize, mode: Mode) -> usize {
+    match mode {
+        Mode::Simulation => {
+            // The circle is split in two so that the elf taking their turn is at the front of `left`
+            // and the elf across from them is at the front of `right`.
+            let mut left: VecDeque<usize> = (1..=elves / 2).collect();
+            let mut right: VecDeque<usize> = (elves / 2 + 1..=elves).collect();
+            while left.len() + right.len() > 1 {
+                right.pop_front();
+                if let Some(elf) = left.pop_front() {
+                    right.push_back(elf);
+                }
+                let half = (left.len() + right.len()) / 2;
+                while left.len() < half {
+                    left.push_back(right.pop_front().unwrap());
+                }
+            }
+            right[0]
+        }
+        Mode::ClosedForm => {
+            let mut power_of_three = 1;
+            while power_of_three * 3 < elves {
+                power_of_three *= 3;
+            }
+            if elves == 1 {
+                1
+            } else if elves <= 2 * power_of_three {
+                elves - power_of_three
+            } else {
+                2 * elves - 3 * power_of_three
+            }
+        }
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let elves = complete(input, parse_usize)?;
+        if elves == 0 {
+            return Err(Error::Invalid("there must be at least one elf".to_string()));
+        }
+        Ok(elves)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(winner_next_door(*input, Mode::ClosedForm)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(winner_across(*input, Mode::ClosedForm)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        for &mode in [Mode::Simulation, Mode::ClosedForm].iter() {
+            assert_eq!(winner_next_door(5, mode), 3);
+            assert_eq!(winner_across(5, mode), 2);
+        }
+    }
+
+    #[test]
+    fn test_closed_form_matches_simulation() {
+        for elves in 1..=500 {
+            assert_eq!(
+                winner_next_door(elves, Mode::ClosedForm),
+                winner_next_door(elves, Mode::Simulation),
+                "next door, {} elves",
+                elves
+            );
+            assert_eq!(
+                winner_across(elves, Mode::ClosedForm),
+                winner_across(elves, Mode::Simulation),
+                "across, {} elves",
+                elves
+            );
+        }
+    }
+}
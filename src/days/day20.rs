@@ -0,0 +1,66 @@
+use crate::error::{Error, Result};
+use crate::interval::IntervalSet;
+use crate::parse::{complete, lines, parse_u32};
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::char, combinator::verify, sequence::separated_pair, IResult};
+
+const MAX_ADDRESS: u64 = u32::MAX as u64;
+
+fn parse_rule(input: &str) -> IResult<&str, (u32, u32)> {
+    let range = separated_pair(parse_u32, char('-'), parse_u32);
+    verify(range, |&(start, end)| start <= end)(input)
+}
+
+fn parse_rules(input: &str) -> IResult<&str, Vec<(u32, u32)>> {
+    lines(parse_rule)(input)
+}
+
+fn allowed(blocked: &IntervalSet, max_address: u64) -> IntervalSet {
+    blocked.complement(0..=max_address)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = IntervalSet;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rules = complete(input, parse_rules)?;
+        let mut blocked = IntervalSet::new();
+        for (start, end) in rules {
+            blocked.insert(start as u64..=end as u64);
+        }
+        Ok(blocked)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let lowest = allowed(input, MAX_ADDRESS)
+            .iter()
+            .next()
+            .ok_or_else(|| Error::Invalid("every address is blocked".to_string()))?;
+        Ok(Answer::from(*lowest.start()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(allowed(input, MAX_ADDRESS).count()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let blocked = Day20::parse("5-8\n0-2\n4-7\n").unwrap();
+        let allowed = allowed(&blocked, 9);
+        assert_eq!(allowed.iter().next(), Some(3..=3));
+        assert_eq!(allowed.count(), 2);
+    }
+
+    #[test]
+    fn test_parse_rejects_reversed_rule() {
+        assert!(Day20::parse("8-5\n").is_err());
+    }
+}
@@ -0,0 +1,248 @@
+use crate::error::Result;
+use crate::parse::{complete, lines, parse_usize};
+use crate::solution::{Answer, Solution};
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::anychar;
+use nom::combinator::{map, verify};
+use nom::sequence::{preceded, separated_pair, terminated};
+use nom::IResult;
+
+const PASSWORD_PART1: &str = "abcdefgh";
+const SCRAMBLED_PART2: &str = "fbgdceah";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    SwapPosition(usize, usize),
+    SwapLetter(char, char),
+    RotateLeft(usize),
+    RotateRight(usize),
+    RotateBasedOn(char),
+    /// Undoes `RotateBasedOn`; it only appears as the result of `invert()`.
+    UnrotateBasedOn(char),
+    Reverse(usize, usize),
+    Move(usize, usize),
+}
+
+/// The number of steps to rotate right for a letter at `index` in `RotateBasedOn`.
+fn rotation_based_on(index: usize) -> usize {
+    if index >= 4 {
+        index + 2
+    } else {
+        index + 1
+    }
+}
+
+impl Operation {
+    fn execute(&self, mut password: Vec<char>) -> Vec<char> {
+        let len = password.len();
+        let position = |password: &[char], letter| password.iter().position(|&c| c == letter);
+        match *self {
+            Operation::SwapPosition(x, y) => password.swap(x, y),
+            Operation::SwapLetter(a, b) => {
+                if let (Some(x), Some(y)) = (position(&password, a), position(&password, b)) {
+                    password.swap(x, y);
+                }
+            }
+            Operation::RotateLeft(n) => password.rotate_left(n % len),
+            Operation::RotateRight(n) => password.rotate_right(n % len),
+            Operation::RotateBasedOn(letter) => {
+                if let Some(index) = position(&password, letter) {
+                    password.rotate_right(rotation_based_on(index) % len);
+                }
+            }
+            Operation::UnrotateBasedOn(letter) => {
+                if let Some(index) = position(&password, letter) {
+                    let original = (0..len).find(|&i| (i + rotation_based_on(i)) % len == index);
+                    if let Some(original) = original {
+                        password.rotate_left(rotation_based_on(original) % len);
+                    }
+                }
+            }
+            Operation::Reverse(x, y) => password[x..=y].reverse(),
+            Operation::Move(x, y) => {
+                let letter = password.remove(x);
+                password.insert(y, letter);
+            }
+        }
+        password
+    }
+
+    /// The operation that undoes this one. `RotateBasedOn` is only invertible when no two letter
+    /// positions end up in the same place, which holds for the puzzle's eight-letter passwords.
+    pub fn invert(&self) -> Operation {
+        match *self {
+            Operation::SwapPosition(x, y) => Operation::SwapPosition(x, y),
+            Operation::SwapLetter(a, b) => Operation::SwapLetter(a, b),
+            Operation::RotateLeft(n) => Operation::RotateRight(n),
+            Operation::RotateRight(n) => Operation::RotateLeft(n),
+            Operation::RotateBasedOn(letter) => Operation::UnrotateBasedOn(letter),
+            Operation::UnrotateBasedOn(letter) => Operation::RotateBasedOn(letter),
+            Operation::Reverse(x, y) => Operation::Reverse(x, y),
+            Operation::Move(x, y) => Operation::Move(y, x),
+        }
+    }
+}
+
+fn scramble(password: &str, operations: &[Operation]) -> String {
+    let mut password: Vec<char> = password.chars().collect();
+    for operation in operations.iter() {
+        password = operation.execute(password);
+    }
+    password.into_iter().collect()
+}
+
+fn unscramble(scrambled: &str, operations: &[Operation]) -> String {
+    let inverted: Vec<Operation> = operations.iter().rev().map(|o| o.invert()).collect();
+    scramble(scrambled, &inverted)
+}
+
+fn parse_position(input: &str) -> IResult<&str, usize> {
+    verify(parse_usize, |&x| x < PASSWORD_PART1.len())(input)
+}
+
+fn parse_letter(input: &str) -> IResult<&str, char> {
+    verify(anychar, char::is_ascii_lowercase)(input)
+}
+
+fn parse_swap_position(input: &str) -> IResult<&str, Operation> {
+    let parser = separated_pair(parse_position, tag(" with position "), parse_position);
+    let parser = preceded(tag("swap position "), parser);
+    map(parser, |(x, y)| Operation::SwapPosition(x, y))(input)
+}
+
+fn parse_swap_letter(input: &str) -> IResult<&str, Operation> {
+    let parser = separated_pair(parse_letter, tag(" with letter "), parse_letter);
+    let parser = preceded(tag("swap letter "), parser);
+    map(parser, |(a, b)| Operation::SwapLetter(a, b))(input)
+}
+
+fn parse_steps(input: &str) -> IResult<&str, usize> {
+    terminated(parse_usize, alt((tag(" steps"), tag(" step"))))(input)
+}
+
+fn parse_rotate_left(input: &str) -> IResult<&str, Operation> {
+    map(
+        preceded(tag("rotate left "), parse_steps),
+        Operation::RotateLeft,
+    )(input)
+}
+
+fn parse_rotate_right(input: &str) -> IResult<&str, Operation> {
+    map(
+        preceded(tag("rotate right "), parse_steps),
+        Operation::RotateRight,
+    )(input)
+}
+
+fn parse_rotate_based_on(input: &str) -> IResult<&str, Operation> {
+    let parser = preceded(tag("rotate based on position of letter "), parse_letter);
+    map(parser, Operation::RotateBasedOn)(input)
+}
+
+fn parse_reverse(input: &str) -> IResult<&str, Operation> {
+    let range = separated_pair(parse_position, tag(" through "), parse_position);
+    let parser = preceded(tag("reverse positions "), verify(range, |&(x, y)| x <= y));
+    map(parser, |(x, y)| Operation::Reverse(x, y))(input)
+}
+
+fn parse_move(input: &str) -> IResult<&str, Operation> {
+    let parser = separated_pair(parse_position, tag(" to position "), parse_position);
+    let parser = preceded(tag("move position "), parser);
+    map(parser, |(x, y)| Operation::Move(x, y))(input)
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    alt((
+        parse_swap_position,
+        parse_swap_letter,
+        parse_rotate_left,
+        parse_rotate_right,
+        parse_rotate_based_on,
+        parse_reverse,
+        parse_move,
+    ))(input)
+}
+
+fn parse_operations(input: &str) -> IResult<&str, Vec<Operation>> {
+    lines(parse_operation)(input)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, parse_operations)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(scramble(PASSWORD_PART1, input)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(unscramble(SCRAMBLED_PART2, input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "swap position 4 with position 0
+swap letter d with letter b
+reverse positions 0 through 4
+rotate left 1 step
+move position 1 to position 4
+move position 3 to position 0
+rotate based on position of letter b
+rotate based on position of letter d
+";
+
+    #[test]
+    fn test_example() {
+        let operations = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(scramble("abcde", &operations), "decab");
+    }
+
+    #[test]
+    fn test_invert() {
+        let mut operations = Vec::new();
+        for x in 0..8 {
+            operations.push(Operation::RotateLeft(x + 3));
+            operations.push(Operation::RotateRight(x * 2));
+            for y in 0..8 {
+                operations.push(Operation::SwapPosition(x, y));
+                operations.push(Operation::Move(x, y));
+                if x <= y {
+                    operations.push(Operation::Reverse(x, y));
+                }
+            }
+        }
+        for letter in PASSWORD_PART1.chars() {
+            operations.push(Operation::RotateBasedOn(letter));
+            operations.push(Operation::SwapLetter(letter, 'c'));
+        }
+
+        for operation in operations.iter() {
+            let scrambled = scramble(PASSWORD_PART1, &[*operation]);
+            assert_eq!(
+                unscramble(&scrambled, &[*operation]),
+                PASSWORD_PART1,
+                "{:?}",
+                operation
+            );
+        }
+
+        let scrambled = scramble(PASSWORD_PART1, &operations);
+        assert_eq!(unscramble(&scrambled, &operations), PASSWORD_PART1);
+    }
+
+    #[test]
+    fn test_parse_rejects_out_of_range_position() {
+        assert!(Day21::parse("swap position 8 with position 0").is_err());
+        assert!(Day21::parse("reverse positions 4 through 2").is_err());
+    }
+}
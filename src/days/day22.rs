@@ -0,0 +1,213 @@
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::parse::{complete, lines, parse_usize};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{char, newline, not_line_ending, space1};
+use nom::combinator::{map, opt, verify};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node {
+    size: usize,
+    used: usize,
+}
+
+impl Node {
+    fn avail(&self) -> usize {
+        self.size - self.used
+    }
+}
+
+type Position = (usize, usize);
+
+/// A node's data together with where it lives, as listed by `df`.
+type Listing = (Position, Node);
+
+/// The storage cluster, along with the position of its only empty node and of the goal data.
+pub struct Cluster {
+    nodes: Grid<Node>,
+    empty: Position,
+    goal: Position,
+}
+
+impl Cluster {
+    fn new(listings: Vec<Listing>) -> Result<Cluster> {
+        let width = listings
+            .iter()
+            .map(|&((x, _y), _n)| x + 1)
+            .max()
+            .unwrap_or(0);
+        let height = listings
+            .iter()
+            .map(|&((_x, y), _n)| y + 1)
+            .max()
+            .unwrap_or(0);
+        let mut nodes: Grid<Option<Node>> = Grid::new(width, height, None);
+        for (position, node) in listings {
+            if nodes[position].replace(node).is_some() {
+                let (x, y) = position;
+                return Err(Error::Invalid(format!("node x{}-y{} listed twice", x, y)));
+            }
+        }
+        if let Some((x, y)) = nodes.positions().find(|&p| nodes[p].is_none()) {
+            return Err(Error::Invalid(format!("node x{}-y{} is missing", x, y)));
+        }
+        let nodes = nodes.map(|node| node.unwrap());
+
+        let mut empty_nodes = nodes.positions().filter(|&p| nodes[p].used == 0);
+        let empty = match (empty_nodes.next(), empty_nodes.next()) {
+            (Some(empty), None) => empty,
+            _ => {
+                return Err(Error::Invalid(
+                    "expected exactly one empty node".to_string(),
+                ))
+            }
+        };
+
+        Ok(Cluster {
+            goal: (width - 1, 0),
+            nodes,
+            empty,
+        })
+    }
+
+    fn viable_pairs(&self) -> usize {
+        let nodes: Vec<Node> = self.nodes.positions().map(|p| self.nodes[p]).collect();
+        let mut count = 0;
+        for (i, a) in nodes.iter().enumerate() {
+            for (j, b) in nodes.iter().enumerate() {
+                if i != j && a.used > 0 && a.used <= b.avail() {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// Nodes holding more data than the empty node can take are never moved.
+    fn is_wall(&self, position: Position) -> bool {
+        self.nodes[position].used > self.nodes[self.empty].size
+    }
+
+    /// Moves are made by shifting data into the empty node, so the search state is the position
+    /// of the empty node together with the position of the goal data.
+    fn fewest_moves(&self) -> Option<usize> {
+        let neighbours = |&(empty, goal): &(Position, Position)| {
+            self.nodes
+                .neighbours(empty)
+                .filter(|&next| !self.is_wall(next))
+                .map(|next| (next, if next == goal { empty } else { goal }))
+                .collect::<Vec<_>>()
+        };
+        search::bfs((self.empty, self.goal), neighbours)
+            .find(|&((_empty, goal), _distance)| goal == (0, 0))
+            .map(|(_state, distance)| distance)
+    }
+
+    pub fn render(&self) -> String {
+        self.nodes.render(|position, _node| {
+            if position == self.goal {
+                'G'
+            } else if position == self.empty {
+                '_'
+            } else if self.is_wall(position) {
+                '#'
+            } else {
+                '.'
+            }
+        })
+    }
+}
+
+fn parse_terabytes(input: &str) -> IResult<&str, usize> {
+    preceded(space1, terminated(parse_usize, char('T')))(input)
+}
+
+fn parse_listing(input: &str) -> IResult<&str, Listing> {
+    let position = pair(
+        preceded(tag("/dev/grid/node-x"), parse_usize),
+        preceded(tag("-y"), parse_usize),
+    );
+    let usage = delimited(space1, parse_usize, char('%'));
+    let parser = tuple((
+        position,
+        parse_terabytes,
+        parse_terabytes,
+        parse_terabytes,
+        usage,
+    ));
+    let parser = verify(parser, |&(_position, size, used, avail, _usage)| {
+        used <= size && size - used == avail
+    });
+    map(parser, |(position, size, used, _avail, _usage)| {
+        (position, Node { size, used })
+    })(input)
+}
+
+fn parse_listings(input: &str) -> IResult<&str, Vec<Listing>> {
+    let command = opt(terminated(tag("root@ebhq-gridcenter# df -h"), newline));
+    let header = tuple((tag("Filesystem"), not_line_ending, newline));
+    preceded(pair(command, header), lines(parse_listing))(input)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Cluster;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Cluster::new(complete(input, parse_listings)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(input.viable_pairs()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        let moves = input
+            .fewest_moves()
+            .ok_or_else(|| Error::Invalid("the goal data cannot reach node x0-y0".to_string()))?;
+        Ok(Answer::from(moves))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%
+";
+
+    #[test]
+    fn test_example() {
+        let cluster = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(cluster.render(), "..G\n._.\n#..");
+        assert_eq!(cluster.viable_pairs(), 7);
+        assert_eq!(cluster.fewest_moves(), Some(7));
+    }
+
+    #[test]
+    fn test_parse_with_command_line() {
+        let input = format!("root@ebhq-gridcenter# df -h\n{}", EXAMPLE);
+        assert!(Day22::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_node() {
+        let input: Vec<&str> = EXAMPLE.lines().filter(|l| !l.contains("x1-y2")).collect();
+        assert!(Day22::parse(&input.join("\n")).is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::assembunny::{self, Instruction, Machine, Register};
+use crate::error::Result;
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+const EGGS_PART1: i64 = 7;
+const EGGS_PART2: i64 = 12;
+
+fn run_with_a(program: &[Instruction], a: i64, optimise: bool) -> i64 {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_optimise(optimise);
+    machine.set_register(Register::A, a);
+    machine.run();
+    machine.register(Register::A)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, assembunny::parse_program)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(run_with_a(input, EGGS_PART1, true)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from(run_with_a(input, EGGS_PART2, true)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a\n";
+        let program = Day23::parse(input).unwrap();
+        assert_eq!(Day23::part1(&program), Ok(Answer::Number(3)));
+    }
+
+    #[test]
+    fn test_optimisation_survives_toggling() {
+        // Computes a * b, then toggles the final jump so that the loop is only run once.
+        let input = "cpy a b\ncpy a d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\n\
+                     jnz d -5\ntgl 1\njnz 1 -7";
+        let program = Day23::parse(input).unwrap();
+        for a in 1..6 {
+            assert_eq!(run_with_a(&program, a, true), a * a);
+            assert_eq!(run_with_a(&program, a, false), a * a);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::parse::{complete, lines};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+use nom::{character::complete::one_of, multi::many1, IResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    Open,
+    Location(usize),
+}
+
+fn cell(c: char) -> Cell {
+    match c {
+        '#' => Cell::Wall,
+        '.' => Cell::Open,
+        _ => Cell::Location(c.to_digit(10).unwrap() as usize),
+    }
+}
+
+fn parse_rows(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    lines(many1(one_of("#.0123456789")))(input)
+}
+
+/// The shortest distance between every pair of numbered locations in the ducts.
+fn distances(ducts: &Grid<Cell>) -> Result<Vec<Vec<usize>>> {
+    let mut locations = Vec::new();
+    for position in ducts.positions() {
+        if let Cell::Location(number) = ducts[position] {
+            locations.push((number, position));
+        }
+    }
+    locations.sort();
+    for (i, &(number, _position)) in locations.iter().enumerate() {
+        if number != i {
+            return Err(Error::Invalid(format!(
+                "location {} is missing or repeated",
+                i
+            )));
+        }
+    }
+    if locations.is_empty() {
+        return Err(Error::Invalid("there is no location 0".to_string()));
+    }
+
+    let mut distances = vec![vec![None; locations.len()]; locations.len()];
+    for &(from, start) in locations.iter() {
+        let neighbours = |&position: &(usize, usize)| {
+            ducts
+                .neighbours(position)
+                .filter(|&next| ducts[next] != Cell::Wall)
+        };
+        for (position, distance) in search::bfs(start, neighbours) {
+            if let Cell::Location(to) = ducts[position] {
+                distances[from][to] = Some(distance);
+            }
+        }
+    }
+
+    distances
+        .into_iter()
+        .enumerate()
+        .map(|(from, row)| {
+            row.into_iter()
+                .collect::<Option<Vec<usize>>>()
+                .ok_or_else(|| Error::Invalid(format!("location {} is cut off", from)))
+        })
+        .collect()
+}
+
+/// The length of the shortest route from location 0 that visits every location, optionally
+/// returning to 0, using the Held–Karp dynamic programme over subsets of visited locations.
+/// Returns `None` if there are no locations at all.
+fn shortest_route(distances: &[Vec<usize>], return_to_start: bool) -> Option<usize> {
+    let count = distances.len();
+    if count == 0 {
+        return None;
+    }
+    let full = (1 << count) - 1;
+
+    // best[visited][last] is the shortest route from 0 that visits exactly `visited`, ending at
+    // `last`.
+    let mut best = vec![vec![usize::MAX; count]; 1 << count];
+    best[1][0] = 0;
+    for visited in 1..=full {
+        if visited & 1 == 0 {
+            continue;
+        }
+        for last in 0..count {
+            let length = best[visited][last];
+            if length == usize::MAX {
+                continue;
+            }
+            for next in 0..count {
+                if visited & (1 << next) != 0 {
+                    continue;
+                }
+                let entry = &mut best[visited | (1 << next)][next];
+                *entry = (*entry).min(length + distances[last][next]);
+            }
+        }
+    }
+
+    (0..count)
+        .map(|last| {
+            let back = if return_to_start {
+                distances[last][0]
+            } else {
+                0
+            };
+            best[full][last] + back
+        })
+        .min()
+}
+
+fn solve(distances: &[Vec<usize>], return_to_start: bool) -> Result<Answer> {
+    let length = shortest_route(distances, return_to_start)
+        .ok_or_else(|| Error::Invalid("there is no location 0".to_string()))?;
+    Ok(Answer::from(length))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Vec<usize>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let rows = complete(input, parse_rows)?;
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(cell).collect())
+            .collect();
+        let ducts = Grid::from_rows(rows)
+            .ok_or_else(|| Error::Invalid("rows differ in length".to_string()))?;
+        distances(&ducts)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        solve(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        solve(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########
+";
+
+    #[test]
+    fn test_example() {
+        let distances = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(distances[0][4], 2);
+        assert_eq!(distances[1][3], 8);
+        assert_eq!(Day24::part1(&distances), Ok(Answer::Number(14)));
+        assert_eq!(Day24::part2(&distances), Ok(Answer::Number(20)));
+    }
+
+    #[test]
+    fn test_parse_rejects_unreachable_location() {
+        assert!(Day24::parse("#####\n#0#1#\n#####").is_err());
+        assert!(Day24::parse("#####\n#0.2#\n#####").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use crate::assembunny::{self, Instruction, Machine, Register};
+use crate::error::{Error, Result};
+use crate::parse::complete;
+use crate::solution::{Answer, Solution};
+
+/// The largest initial value of register a that is tried before giving up.
+const MAX_A: i64 = 1 << 16;
+
+/// Whether the program transmits 0, 1, 0, 1, ... forever when started with `a` in register a.
+///
+/// The machine is deterministic, so once it is about to transmit from a state it has been in
+/// before, expecting the same bit, the signal is proven to repeat indefinitely. A machine that
+/// stops transmitting, by halting or by looping silently, does not produce a clock signal.
+fn produces_clock_signal(program: &[Instruction], a: i64) -> bool {
+    let mut machine = Machine::new(program.to_vec());
+    machine.set_register(Register::A, a);
+
+    let mut seen = HashSet::new();
+    let mut expected = 0;
+    while seen.insert((machine.state(), expected)) {
+        if machine.next_output() != Some(expected) {
+            return false;
+        }
+        expected = 1 - expected;
+    }
+    true
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        complete(input, assembunny::parse_program)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        let a = (1..=MAX_A)
+            .find(|&a| produces_clock_signal(input, a))
+            .ok_or_else(|| {
+                let message = format!("no value of a up to {} produces a clock signal", MAX_A);
+                Error::Invalid(message)
+            })?;
+        Ok(Answer::from(a))
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::from("Merry Christmas!"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alternating_signal() {
+        // Transmits a, then flips a between 0 and 1.
+        let input = "out a\njnz a 3\ninc a\njnz 1 -3\ndec a\njnz 1 -5";
+        let program = Day25::parse(input).unwrap();
+        assert!(produces_clock_signal(&program, 0));
+        assert!(!produces_clock_signal(&program, 1));
+        assert!(!produces_clock_signal(&program, 2));
+    }
+
+    #[test]
+    fn test_signal_that_breaks_off() {
+        // Transmits 0, 1 a times and then 1, 1.
+        let input = "cpy a b\nout 0\nout 1\ndec b\njnz b -3\nout 1\nout 1";
+        let program = Day25::parse(input).unwrap();
+        assert!(!produces_clock_signal(&program, 5));
+    }
+
+    #[test]
+    fn test_halting_program() {
+        let program = Day25::parse("out 0\nout 1").unwrap();
+        assert!(!produces_clock_signal(&program, 0));
+    }
+
+    #[test]
+    fn test_program_that_falls_silent() {
+        let program = Day25::parse("out 0\nout 1\njnz 1 0").unwrap();
+        assert!(!produces_clock_signal(&program, 0));
+    }
+}
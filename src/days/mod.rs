@@ -0,0 +1,139 @@
+use crate::solution::{self, Runner};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub struct Day {
+    pub number: u32,
+    pub run: Runner,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        run: solution::run::<day01::Day01>,
+    },
+    Day {
+        number: 2,
+        run: solution::run::<day02::Day02>,
+    },
+    Day {
+        number: 3,
+        run: solution::run::<day03::Day03>,
+    },
+    Day {
+        number: 4,
+        run: solution::run::<day04::Day04>,
+    },
+    Day {
+        number: 5,
+        run: solution::run::<day05::Day05>,
+    },
+    Day {
+        number: 6,
+        run: solution::run::<day06::Day06>,
+    },
+    Day {
+        number: 7,
+        run: solution::run::<day07::Day07>,
+    },
+    Day {
+        number: 8,
+        run: solution::run::<day08::Day08>,
+    },
+    Day {
+        number: 9,
+        run: solution::run::<day09::Day09>,
+    },
+    Day {
+        number: 10,
+        run: solution::run::<day10::Day10>,
+    },
+    Day {
+        number: 11,
+        run: solution::run::<day11::Day11>,
+    },
+    Day {
+        number: 12,
+        run: solution::run::<day12::Day12>,
+    },
+    Day {
+        number: 13,
+        run: solution::run::<day13::Day13>,
+    },
+    Day {
+        number: 14,
+        run: solution::run::<day14::Day14>,
+    },
+    Day {
+        number: 15,
+        run: solution::run::<day15::Day15>,
+    },
+    Day {
+        number: 16,
+        run: solution::run::<day16::Day16>,
+    },
+    Day {
+        number: 17,
+        run: solution::run::<day17::Day17>,
+    },
+    Day {
+        number: 18,
+        run: solution::run::<day18::Day18>,
+    },
+    Day {
+        number: 19,
+        run: solution::run::<day19::Day19>,
+    },
+    Day {
+        number: 20,
+        run: solution::run::<day20::Day20>,
+    },
+    Day {
+        number: 21,
+        run: solution::run::<day21::Day21>,
+    },
+    Day {
+        number: 22,
+        run: solution::run::<day22::Day22>,
+    },
+    Day {
+        number: 23,
+        run: solution::run::<day23::Day23>,
+    },
+    Day {
+        number: 24,
+        run: solution::run::<day24::Day24>,
+    },
+    Day {
+        number: 25,
+        run: solution::run::<day25::Day25>,
+    },
+];
+
+pub fn find(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
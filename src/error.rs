@@ -0,0 +1,50 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The input does not match the expected format.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed but describes a puzzle that cannot be solved.
+    Invalid(String),
+}
+
+impl Error {
+    /// A parse error located at the start of `rest`, which must be a suffix of `input`.
+    pub fn parse_at(input: &str, rest: &str, message: impl Into<String>) -> Error {
+        let consumed = &input[..input.len() - rest.len()];
+        let line = consumed.matches('\n').count() + 1;
+        let line_start = consumed.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = consumed[line_start..].chars().count() + 1;
+
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Could not parse input at line {}, column {}: {}",
+                line, column, message
+            ),
+            Error::Invalid(message) => write!(f, "Invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,35 @@
+pub type Hash = [u8; 16];
+
+const HEX_CHARACTERS: [char; 16] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
+];
+
+pub fn md5(input: impl AsRef<[u8]>) -> Hash {
+    md5::compute(input).0
+}
+
+pub fn md5_hex(input: impl AsRef<[u8]>) -> String {
+    format!("{:x}", md5::compute(input))
+}
+
+/// The hex digest of `input`, re-hashed `rounds` more times as in key stretching.
+pub fn md5_hex_stretched(input: impl AsRef<[u8]>, rounds: usize) -> String {
+    let mut hash = md5_hex(input);
+    for _i in 0..rounds {
+        hash = md5_hex(hash);
+    }
+    hash
+}
+
+pub fn nibble(hash: &Hash, i: usize) -> u8 {
+    let byte = hash[i / 2];
+    match i % 2 {
+        0 => byte / 16,
+        1 => byte % 16,
+        _ => unreachable!(),
+    }
+}
+
+pub fn hex_character(hash: &Hash, i: usize) -> char {
+    HEX_CHARACTERS[nibble(hash, i) as usize]
+}
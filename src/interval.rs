@@ -0,0 +1,140 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint, non-adjacent inclusive ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    ranges: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Adds every value in `range` to the set, merging it with any range it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // The ranges are sorted and disjoint, so both their starts and their ends are increasing,
+        // and the ranges that overlap or touch the new one form a contiguous run.
+        let first = self
+            .ranges
+            .partition_point(|&(_s, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _e)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, Some((start, end)));
+    }
+
+    /// Adds every value in `other` to the set.
+    pub fn merge(&mut self, other: &IntervalSet) {
+        for range in other.iter() {
+            self.insert(range);
+        }
+    }
+
+    /// The values in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> IntervalSet {
+        let (low, high) = bounds.into_inner();
+        let mut result = IntervalSet::new();
+        let mut next = Some(low);
+        for &(start, end) in self.ranges.iter() {
+            let from = match next {
+                Some(from) if from <= high => from,
+                _ => break,
+            };
+            if start > from {
+                result.insert(from..=(start - 1).min(high));
+            }
+            if end >= from {
+                next = end.checked_add(1);
+            }
+        }
+        if let Some(from) = next {
+            result.insert(from..=high);
+        }
+        result
+    }
+
+    /// The number of values in the set. This is a `u128` because a set holding every `u64` has
+    /// one more value than `u64::MAX`.
+    pub fn count(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| u128::from(end - start) + 1)
+            .sum()
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let i = self.ranges.partition_point(|&(_s, e)| e < value);
+        matches!(self.ranges.get(i), Some(&(start, _end)) if start <= value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet) -> Vec<RangeInclusive<u64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert_merges_overlapping_and_adjacent() {
+        let mut set = IntervalSet::new();
+        set.insert(10..=12);
+        set.insert(1..=2);
+        set.insert(5..=6);
+        assert_eq!(ranges(&set), vec![1..=2, 5..=6, 10..=12]);
+
+        set.insert(3..=4);
+        assert_eq!(ranges(&set), vec![1..=6, 10..=12]);
+        set.insert(8..=20);
+        assert_eq!(ranges(&set), vec![1..=6, 8..=20]);
+        set.insert(0..=30);
+        assert_eq!(ranges(&set), vec![0..=30]);
+        assert_eq!(set.count(), 31);
+    }
+
+    #[test]
+    fn test_complement() {
+        let mut set = IntervalSet::new();
+        set.insert(2..=3);
+        set.insert(6..=8);
+        assert_eq!(ranges(&set.complement(0..=9)), vec![0..=1, 4..=5, 9..=9]);
+        assert_eq!(ranges(&set.complement(3..=6)), vec![4..=5]);
+        assert_eq!(ranges(&set.complement(2..=8)), vec![4..=5]);
+        assert_eq!(ranges(&IntervalSet::new().complement(1..=4)), vec![1..=4]);
+
+        set.insert(0..=u64::MAX);
+        assert_eq!(set.complement(0..=u64::MAX).count(), 0);
+        assert_eq!(set.count(), u128::from(u64::MAX) + 1);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut left = IntervalSet::new();
+        left.insert(0..=4);
+        let mut right = IntervalSet::new();
+        right.insert(5..=7);
+        right.insert(10..=10);
+        left.merge(&right);
+        assert_eq!(ranges(&left), vec![0..=7, 10..=10]);
+        assert!(left.contains(0));
+        assert!(left.contains(7));
+        assert!(left.contains(10));
+        assert!(!left.contains(9));
+        assert!(!left.contains(11));
+    }
+}
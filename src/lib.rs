@@ -0,0 +1,12 @@
+pub mod answers;
+pub mod assembunny;
+pub mod days;
+pub mod error;
+pub mod grid;
+pub mod hash;
+pub mod interval;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod table;
+pub mod turtle;
@@ -0,0 +1,81 @@
+use crate::error::{Error, Result};
+
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::ErrorKind,
+    sequence::{pair, terminated},
+    IResult,
+};
+
+pub fn parse_u32(input: &str) -> IResult<&str, u32> {
+    map_res(digit1, |s: &str| s.parse())(input)
+}
+
+pub fn parse_u64(input: &str) -> IResult<&str, u64> {
+    map_res(digit1, |s: &str| s.parse())(input)
+}
+
+pub fn parse_usize(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, |s: &str| s.parse())(input)
+}
+
+pub fn parse_i32(input: &str) -> IResult<&str, i32> {
+    map_res(recognize(pair(opt(char('-')), digit1)), |s: &str| s.parse())(input)
+}
+
+pub fn parse_i64(input: &str) -> IResult<&str, i64> {
+    map_res(recognize(pair(opt(char('-')), digit1)), |s: &str| s.parse())(input)
+}
+
+/// Parses one `element` per line. Unlike `separated_list`, a line that fails to parse is an
+/// error rather than the end of the list, so the error points into the offending line.
+pub fn lines<'a, T, F>(element: F) -> impl Fn(&'a str) -> IResult<&'a str, Vec<T>>
+where
+    F: Fn(&'a str) -> IResult<&'a str, T>,
+{
+    move |input: &'a str| {
+        let (mut rest, first) = element(input)?;
+        let mut result = vec![first];
+        loop {
+            match char::<&str, (&str, ErrorKind)>('\n')(rest) {
+                Ok((next_line, _newline)) if !next_line.is_empty() => {
+                    let (next_rest, value) = element(next_line)?;
+                    result.push(value);
+                    rest = next_rest;
+                }
+                _ => return Ok((rest, result)),
+            }
+        }
+    }
+}
+
+fn unexpected(rest: &str) -> String {
+    match rest.lines().next() {
+        None => "unexpected end of input".to_string(),
+        Some("") => "unexpected end of line".to_string(),
+        Some(line) if line.chars().count() > 20 => {
+            let start: String = line.chars().take(20).collect();
+            format!("unexpected \"{}...\"", start)
+        }
+        Some(line) => format!("unexpected \"{}\"", line),
+    }
+}
+
+/// Runs `parser` over the whole of `input`, allowing a single trailing newline. Anything left
+/// over is an error, reported with its line and column.
+pub fn complete<'a, T, F>(input: &'a str, parser: F) -> Result<T>
+where
+    F: Fn(&'a str) -> IResult<&'a str, T>,
+{
+    let parser = all_consuming(terminated(parser, opt(char('\n'))));
+    match parser(input) {
+        Ok((_rest, value)) => Ok(value),
+        Err(nom::Err::Error((rest, _kind))) | Err(nom::Err::Failure((rest, _kind))) => {
+            Err(Error::parse_at(input, rest, unexpected(rest)))
+        }
+        Err(nom::Err::Incomplete(_needed)) => {
+            Err(Error::parse_at(input, "", "unexpected end of input"))
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Breadth-first traversal that yields every reachable node together with its distance from the
+/// start, in order of increasing distance.
+pub struct Bfs<N, F> {
+    queue: VecDeque<(N, usize)>,
+    visited: HashSet<N>,
+    neighbours: F,
+}
+
+pub fn bfs<N, F, I>(start: N, neighbours: F) -> Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut queue = VecDeque::new();
+    let mut visited = HashSet::new();
+    visited.insert(start.clone());
+    queue.push_back((start, 0));
+    Bfs {
+        queue,
+        visited,
+        neighbours,
+    }
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance) = self.queue.pop_front()?;
+        for next in (self.neighbours)(&node) {
+            if self.visited.insert(next.clone()) {
+                self.queue.push_back((next, distance + 1));
+            }
+        }
+        Some((node, distance))
+    }
+}
+
+/// Breadth-first traversal without a visited set, for graphs where no node can be reached twice,
+/// such as trees of states that each record the path taken to them. Yields every reachable node
+/// together with its distance from the start, in order of increasing distance.
+pub struct BfsTree<N, F> {
+    queue: VecDeque<(N, usize)>,
+    neighbours: F,
+}
+
+pub fn bfs_tree<N, F, I>(start: N, neighbours: F) -> BfsTree<N, F>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+    BfsTree { queue, neighbours }
+}
+
+impl<N, F, I> Iterator for BfsTree<N, F>
+where
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance) = self.queue.pop_front()?;
+        for next in (self.neighbours)(&node) {
+            self.queue.push_back((next, distance + 1));
+        }
+        Some((node, distance))
+    }
+}
+
+/// The nodes along a shortest path from `start` to the first node satisfying `is_goal`, including
+/// both ends, found by breadth-first search.
+pub fn shortest_path<N, F, I, G>(start: N, mut neighbours: F, is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: Fn(&N) -> bool,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in neighbours(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+struct Candidate<N> {
+    node: N,
+    distance: usize,
+    estimate: usize,
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.estimate
+            .cmp(&other.estimate)
+            .reverse()
+            .then(self.distance.cmp(&other.distance))
+    }
+}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A* search over a graph with unit edge weights. Returns the length of the shortest path from
+/// `start` to any node satisfying `is_goal`, provided `heuristic` never overestimates.
+pub fn astar<N, F, I, H, G>(start: N, mut neighbours: F, heuristic: H, is_goal: G) -> Option<usize>
+where
+    N: Clone + Eq + Hash,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    H: Fn(&N) -> usize,
+    G: Fn(&N) -> bool,
+{
+    let mut visited: HashSet<N> = HashSet::new();
+    let mut queue: BinaryHeap<Candidate<N>> = BinaryHeap::new();
+
+    queue.push(Candidate {
+        estimate: heuristic(&start),
+        node: start,
+        distance: 0,
+    });
+
+    while let Some(current) = queue.pop() {
+        if is_goal(&current.node) {
+            return Some(current.distance);
+        }
+        if !visited.insert(current.node.clone()) {
+            continue;
+        }
+        for next in neighbours(&current.node) {
+            if !visited.contains(&next) {
+                queue.push(Candidate {
+                    estimate: current.distance + 1 + heuristic(&next),
+                    node: next,
+                    distance: current.distance + 1,
+                });
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,124 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::error::Result;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Answer {
+        Answer::Number(i64::from(n))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Answer {
+        Answer::Number(i64::from(n))
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Answer {
+        Answer::Number(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Answer {
+        Answer::from(u128::from(n))
+    }
+}
+
+/// Unsigned values can exceed `i64::MAX`, so those are kept exact as text rather than truncated.
+impl From<u128> for Answer {
+    fn from(n: u128) -> Answer {
+        match i64::try_from(n) {
+            Ok(n) => Answer::Number(n),
+            Err(_e) => Answer::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Answer {
+        Answer::from(n as u128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A puzzle solution: the input is parsed once and then shared by both parts. A part fails with
+/// `Error::Invalid` when the input parses but has no answer.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A type-erased entry point for a solution, so days can be stored side by side in a registry.
+pub type Runner = fn(&str, &[Part]) -> Result<Vec<(Part, Answer)>>;
+
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>> {
+    let input = S::parse(input)?;
+    parts
+        .iter()
+        .map(|&part| match part {
+            Part::One => Ok((part, S::part1(&input)?)),
+            Part::Two => Ok((part, S::part2(&input)?)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_large_answers_are_exact() {
+        assert_eq!(Answer::from(42u128), Answer::Number(42));
+        let count = u128::from(u64::MAX) + 1;
+        assert_eq!(Answer::from(count).to_string(), "18446744073709551616");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+}
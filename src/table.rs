@@ -0,0 +1,147 @@
+use crate::error::{Error, Result};
+use crate::parse::lines;
+
+use nom::{
+    character::complete::space0,
+    multi::many1,
+    sequence::{delimited, terminated},
+    IResult,
+};
+
+/// A table of values with the same number of columns in every row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table<T> {
+    columns: usize,
+    rows: Vec<Vec<T>>,
+}
+
+impl<T: Copy> Table<T> {
+    pub fn new(rows: Vec<Vec<T>>) -> Result<Table<T>> {
+        let columns = rows.first().map_or(0, |row| row.len());
+        if let Some(i) = rows.iter().position(|row| row.len() != columns) {
+            return Err(Error::Invalid(format!(
+                "row {} has {}, but row 1 has {}",
+                i + 1,
+                count(rows[i].len(), "column"),
+                columns
+            )));
+        }
+        Ok(Table { columns, rows })
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        &self.rows
+    }
+
+    /// Reads the table down its columns instead of across its rows: each block of `group_size`
+    /// rows yields one group per column, holding that column's values from top to bottom.
+    pub fn transposed(&self, group_size: usize) -> Result<Vec<Vec<T>>> {
+        if group_size == 0 {
+            return Err(Error::Invalid("group size must be positive".to_string()));
+        }
+        let blocks = self.rows.chunks_exact(group_size);
+        let tail = blocks.remainder().len();
+        if tail > 0 {
+            return Err(Error::Invalid(format!(
+                "table has {}, which is not a multiple of the group size {}; the last {} \
+                 not fill a group",
+                count(self.rows.len(), "row"),
+                group_size,
+                if tail == 1 {
+                    "row does".to_string()
+                } else {
+                    format!("{} rows do", tail)
+                }
+            )));
+        }
+
+        let mut groups = Vec::new();
+        for block in blocks {
+            for column in 0..self.columns {
+                groups.push(block.iter().map(|row| row[column]).collect());
+            }
+        }
+        Ok(groups)
+    }
+}
+
+fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("{} {}", n, noun)
+    } else {
+        format!("{} {}s", n, noun)
+    }
+}
+
+/// Parses one row of values per line, separated by spaces.
+pub fn parse_rows<'a, T, F>(element: F) -> impl Fn(&'a str) -> IResult<&'a str, Vec<Vec<T>>>
+where
+    F: Fn(&'a str) -> IResult<&'a str, T>,
+{
+    let row = many1(delimited(space0, element, space0));
+    lines(terminated(row, space0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{complete, parse_u32};
+
+    fn table(input: &str) -> Table<u32> {
+        Table::new(complete(input, parse_rows(parse_u32)).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let table = table("  1  2\n 3 4 \n5 6");
+        assert_eq!(table.columns(), 2);
+        assert_eq!(table.rows(), &[vec![1, 2], vec![3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn test_new_rejects_ragged_rows() {
+        let rows = complete("1 2\n3\n", parse_rows(parse_u32)).unwrap();
+        assert_eq!(
+            Table::new(rows),
+            Err(Error::Invalid(
+                "row 2 has 1 column, but row 1 has 2".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_transposed() {
+        let table = table("1 5\n2 6\n3 7\n4 8");
+        assert_eq!(
+            table.transposed(2).unwrap(),
+            vec![vec![1, 2], vec![5, 6], vec![3, 4], vec![7, 8]]
+        );
+        assert_eq!(
+            table.transposed(4).unwrap(),
+            vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]
+        );
+        assert_eq!(table.transposed(1).unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_transposed_rejects_ragged_tail() {
+        let table = table("1 5\n2 6\n3 7\n4 8");
+        let error = table.transposed(3).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid input: table has 4 rows, which is not a multiple of the group size 3; \
+             the last row does not fill a group"
+        );
+        let error = table.transposed(6).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid input: table has 4 rows, which is not a multiple of the group size 6; \
+             the last 4 rows do not fill a group"
+        );
+        assert!(table.transposed(0).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::grid::{Direction, Turn};
+use crate::parse::parse_i64;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{map, opt, value},
+    multi::separated_nonempty_list,
+    sequence::{pair, preceded},
+    IResult,
+};
+
+pub type Point = (i64, i64);
+
+/// One of the eight compass headings, in clockwise order. The y axis points down, as for
+/// `Direction`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Heading {
+    #[default]
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+const HEADINGS: [Heading; 8] = [
+    Heading::Up,
+    Heading::UpRight,
+    Heading::Right,
+    Heading::DownRight,
+    Heading::Down,
+    Heading::DownLeft,
+    Heading::Left,
+    Heading::UpLeft,
+];
+
+impl Heading {
+    fn index(self) -> usize {
+        HEADINGS.iter().position(|&h| h == self).unwrap()
+    }
+
+    /// Rotates clockwise by `eighths` eighths of a full turn, or anticlockwise if negative.
+    pub fn rotate(self, eighths: i32) -> Heading {
+        HEADINGS[(self.index() as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        match self {
+            Heading::Up => (0, -1),
+            Heading::UpRight => (1, -1),
+            Heading::Right => (1, 0),
+            Heading::DownRight => (1, 1),
+            Heading::Down => (0, 1),
+            Heading::DownLeft => (-1, 1),
+            Heading::Left => (-1, 0),
+            Heading::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Heading {
+    fn from(direction: Direction) -> Heading {
+        match direction {
+            Direction::Up => Heading::Up,
+            Direction::Down => Heading::Down,
+            Direction::Left => Heading::Left,
+            Direction::Right => Heading::Right,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    /// `L5` or `R5`: turn, then walk.
+    Turn(Turn, i64),
+    /// `F5`: walk without turning.
+    Forward(i64),
+    /// `B` or `B5`: turn around, then walk.
+    Reverse(i64),
+    /// `D`: switch between turning by right angles and by half right angles.
+    Diagonal,
+}
+
+/// A walker on the integer lattice that starts at the origin facing up.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Turtle {
+    position: Point,
+    heading: Heading,
+    diagonal: bool,
+}
+
+impl Turtle {
+    pub fn new() -> Turtle {
+        Turtle::default()
+    }
+
+    pub fn position(&self) -> Point {
+        self.position
+    }
+
+    pub fn heading(&self) -> Heading {
+        self.heading
+    }
+
+    /// Carries out the turning part of `instruction` and returns the distance to walk next.
+    pub fn orient(&mut self, instruction: &Instruction) -> i64 {
+        let right_angle = if self.diagonal { 1 } else { 2 };
+        match *instruction {
+            Instruction::Turn(Turn::Left, distance) => {
+                self.heading = self.heading.rotate(-right_angle);
+                distance
+            }
+            Instruction::Turn(Turn::Right, distance) => {
+                self.heading = self.heading.rotate(right_angle);
+                distance
+            }
+            Instruction::Forward(distance) => distance,
+            Instruction::Reverse(distance) => {
+                self.heading = self.heading.rotate(4);
+                distance
+            }
+            Instruction::Diagonal => {
+                self.diagonal = !self.diagonal;
+                0
+            }
+        }
+    }
+
+    /// Moves `distance` steps along the current heading, or backwards if negative.
+    pub fn walk(&mut self, distance: i64) {
+        let (dx, dy) = self.heading.offset();
+        self.position.0 += dx * distance;
+        self.position.1 += dy * distance;
+    }
+
+    pub fn execute(&mut self, instruction: &Instruction) {
+        let distance = self.orient(instruction);
+        self.walk(distance);
+    }
+}
+
+fn parse_turn(input: &str) -> IResult<&str, Turn> {
+    alt((value(Turn::Left, char('L')), value(Turn::Right, char('R'))))(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        map(pair(parse_turn, parse_i64), |(turn, distance)| {
+            Instruction::Turn(turn, distance)
+        }),
+        map(preceded(char('F'), parse_i64), Instruction::Forward),
+        map(preceded(char('B'), opt(parse_i64)), |distance| {
+            Instruction::Reverse(distance.unwrap_or(0))
+        }),
+        value(Instruction::Diagonal, char('D')),
+    ))(input)
+}
+
+pub fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    separated_nonempty_list(tag(", "), parse_instruction)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::complete;
+
+    fn run(input: &str) -> Turtle {
+        let mut turtle = Turtle::new();
+        for instruction in complete(input, parse_instructions).unwrap().iter() {
+            turtle.execute(instruction);
+        }
+        turtle
+    }
+
+    #[test]
+    fn test_parse_instructions() {
+        let instructions = complete("R2, F3, B, B4, D, L1", parse_instructions).unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Turn(Turn::Right, 2),
+                Instruction::Forward(3),
+                Instruction::Reverse(0),
+                Instruction::Reverse(4),
+                Instruction::Diagonal,
+                Instruction::Turn(Turn::Left, 1),
+            ]
+        );
+        assert!(complete("X2", parse_instructions).is_err());
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Heading::Up.rotate(-1), Heading::UpLeft);
+        assert_eq!(Heading::UpLeft.rotate(3), Heading::Right);
+        assert_eq!(Heading::Left.rotate(12), Heading::Right);
+        assert_eq!(Heading::from(Direction::Down), Heading::Down);
+    }
+
+    #[test]
+    fn test_execute() {
+        assert_eq!(run("R2, L3").position(), (2, -3));
+        assert_eq!(run("F3, B, F1").position(), (0, -2));
+        assert_eq!(run("B2").heading(), Heading::Down);
+
+        let turtle = run("D, R2, R2, D, L1");
+        assert_eq!(turtle.position(), (4, -3));
+        assert_eq!(turtle.heading(), Heading::Up);
+    }
+}